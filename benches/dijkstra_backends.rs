@@ -0,0 +1,53 @@
+//! Compares the hash map and dense dijkstra backends on the day10
+//! light toggling puzzles, exploring the full state space of each.
+//!
+//! cargo bench --bench dijkstra_backends
+
+use std::{fs, hint::black_box, time::Instant};
+
+use anyhow::Error;
+use aoc2025::{
+    days::{
+        Solution,
+        day10::{Day10, Puzzle},
+    },
+    dense_dijkstra::DenseDijkstraMap,
+    dijkstra::{DijkstraConfig, DijkstraMap},
+};
+
+const ITERATIONS: usize = 20;
+
+fn bench(name: &str, puzzles: &[Puzzle], run: impl Fn(&Puzzle) -> usize) {
+    let mut times = vec![];
+    let mut settled = 0;
+    for _ in 0..ITERATIONS {
+        let timer = Instant::now();
+        settled = puzzles.iter().map(|p| black_box(run(p))).sum();
+        times.push(timer.elapsed().as_secs_f64() * 1000.0);
+    }
+    times.sort_by(f64::total_cmp);
+    println!(
+        "{name:>8}: settled {settled}, min {:.3}ms, median {:.3}ms, max {:.3}ms",
+        times[0],
+        times[times.len() / 2],
+        times[times.len() - 1]
+    );
+}
+
+pub fn main() -> Result<(), Error> {
+    let input = fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/data/day10"))?;
+    let data = Day10::parse(&input)?;
+    let puzzles = data.puzzles();
+
+    bench("hash map", puzzles, |puzzle| {
+        let mut dmap = DijkstraMap::<_, i32>::new(puzzle, DijkstraConfig::default());
+        dmap.run((0, 0)).len()
+    });
+
+    bench("dense", puzzles, |puzzle| {
+        let mut dmap = DenseDijkstraMap::<_, i32>::new(puzzle, DijkstraConfig::default());
+        dmap.run((0, 0)).len()
+    });
+
+    Ok(())
+}
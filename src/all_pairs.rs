@@ -0,0 +1,341 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    hash::Hash,
+    ops::Add,
+};
+
+use nalgebra::{DMatrix, Scalar};
+use num::Zero;
+
+use crate::dijkstra::{DijkstraConfig, DijkstraInput, DijkstraMap, PathError};
+
+/// Best costs and paths between every pair of a fixed set of nodes,
+/// the "interesting" nodes of a larger graph. The paths may go
+/// through nodes outside the set.
+#[derive(Debug, Clone)]
+pub struct AllPairs<C, I>
+where
+    C: Scalar,
+{
+    nodes: Vec<I>,
+    positions: HashMap<I, usize>,
+    /// `distances[(from, to)]`, `None` when `to` can not be reached.
+    distances: DMatrix<Option<C>>,
+    /// `prev[from]` maps every node on a best path from `nodes[from]`
+    /// to the node before it on that path.
+    prev: Vec<HashMap<I, I>>,
+}
+
+/// Whether [`floyd_warshall`] is expected to beat running Dijkstra
+/// from every node, for a graph of `nodes` nodes and `edges` moves.
+/// Floyd–Warshall always does `nodes³` steps, the repeated searches
+/// roughly `nodes · edges · log(nodes)`.
+pub fn prefers_floyd_warshall(nodes: usize, edges: usize) -> bool {
+    let log = (usize::BITS - nodes.leading_zeros()) as usize;
+    edges * log >= nodes * nodes
+}
+
+/// Picks [`floyd_warshall`] or [`repeated_dijkstra`] depending on how
+/// dense the graph over `nodes` is. Floyd–Warshall is only picked when
+/// no move leaves the node set, as it would miss paths through other
+/// nodes. Costs must not be negative.
+pub fn all_pairs<T>(dijkstra_input: &T, nodes: &[T::Index]) -> AllPairs<T::Cost, T::Index>
+where
+    T: DijkstraInput,
+    T::Cost: Scalar + Ord + Zero + Add<Output = T::Cost> + Copy,
+    T::Index: Eq + PartialOrd + Debug + Hash + Copy,
+    (T::Cost, T::Index): Ord,
+{
+    let positions = AllPairs::<T::Cost, T::Index>::positions(nodes);
+    let mut edges = 0;
+    let mut closed = true;
+    for x in nodes {
+        dijkstra_input.for_each_adjacent(x, |_, index| {
+            edges += 1;
+            closed &= positions.contains_key(&index);
+        });
+    }
+
+    if closed && prefers_floyd_warshall(nodes.len(), edges) {
+        floyd_warshall(dijkstra_input, nodes)
+    } else {
+        repeated_dijkstra(dijkstra_input, nodes)
+    }
+}
+
+/// All pairs best paths with the Floyd–Warshall algorithm on a dense
+/// matrix. Only moves between nodes of the set are seen, so the set
+/// must be closed under adjacency (every node adjacent to a listed
+/// node is listed too) for the paths to be the best ones in the whole
+/// graph. Negative costs are fine as long as they do not form a cycle
+/// with a negative total cost.
+pub fn floyd_warshall<T>(dijkstra_input: &T, nodes: &[T::Index]) -> AllPairs<T::Cost, T::Index>
+where
+    T: DijkstraInput,
+    T::Cost: Scalar + Ord + Zero + Add<Output = T::Cost> + Copy,
+    T::Index: Eq + Hash + Copy,
+{
+    let mut all_pairs = AllPairs::empty(nodes);
+    let n = nodes.len();
+    // `prev[(from, to)]` is the node before `to` on the best path
+    let mut prev = DMatrix::<Option<usize>>::from_element(n, n, None);
+    for (from, x) in nodes.iter().enumerate() {
+        all_pairs.distances[(from, from)] = Some(T::Cost::zero());
+        dijkstra_input.for_each_adjacent(x, |cost, index| {
+            let Some(&to) = all_pairs.positions.get(&index) else {
+                return;
+            };
+            // keep the cheapest of parallel moves
+            if to != from && all_pairs.distances[(from, to)].is_none_or(|best| cost < best) {
+                all_pairs.distances[(from, to)] = Some(cost);
+                prev[(from, to)] = Some(from);
+            }
+        });
+    }
+
+    for via in 0..n {
+        for from in 0..n {
+            let Some(first) = all_pairs.distances[(from, via)] else {
+                continue;
+            };
+            for to in 0..n {
+                let Some(second) = all_pairs.distances[(via, to)] else {
+                    continue;
+                };
+                let alt_cost = first + second;
+                if all_pairs.distances[(from, to)].is_none_or(|best| alt_cost < best) {
+                    all_pairs.distances[(from, to)] = Some(alt_cost);
+                    prev[(from, to)] = prev[(via, to)];
+                }
+            }
+        }
+    }
+
+    for from in 0..n {
+        for to in 0..n {
+            if let Some(before) = prev[(from, to)] {
+                all_pairs.prev[from].insert(nodes[to], nodes[before]);
+            }
+        }
+    }
+    all_pairs
+}
+
+/// All pairs best paths by running [`DijkstraMap`] from every node,
+/// the better choice for sparse graphs. Each search goes through the
+/// whole graph and stops once every node of the set is settled. Costs
+/// must not be negative.
+pub fn repeated_dijkstra<T>(dijkstra_input: &T, nodes: &[T::Index]) -> AllPairs<T::Cost, T::Index>
+where
+    T: DijkstraInput,
+    T::Cost: Scalar + Ord + Zero + Add<Output = T::Cost> + Copy,
+    T::Index: Eq + PartialOrd + Debug + Hash + Copy,
+    (T::Cost, T::Index): Ord,
+{
+    let mut all_pairs = AllPairs::empty(nodes);
+
+    for (from, x) in nodes.iter().enumerate() {
+        let settled = RefCell::new(HashSet::new());
+        let mut dmap = DijkstraMap::<_, T::Index>::new(dijkstra_input, DijkstraConfig::default());
+        let (_, result) = dmap.run_until((T::Cost::zero(), *x), |idx| {
+            let mut settled = settled.borrow_mut();
+            if all_pairs.positions.contains_key(idx) {
+                settled.insert(*idx);
+            }
+            settled.len() == nodes.len()
+        });
+
+        // only keep the nodes on the paths to the set
+        let prev = &mut all_pairs.prev[from];
+        for (to, node) in nodes.iter().enumerate() {
+            let Some((cost, _)) = result.get(node) else {
+                continue;
+            };
+            all_pairs.distances[(from, to)] = Some(*cost);
+            let mut cur = *node;
+            while cur != *x && !prev.contains_key(&cur) {
+                let before = result[&cur].1;
+                prev.insert(cur, before);
+                cur = before;
+            }
+        }
+    }
+
+    all_pairs
+}
+
+impl<C, I> AllPairs<C, I>
+where
+    C: Scalar + Copy,
+    I: Eq + Hash + Copy,
+{
+    fn positions(nodes: &[I]) -> HashMap<I, usize> {
+        nodes.iter().enumerate().map(|(i, x)| (*x, i)).collect()
+    }
+
+    fn empty(nodes: &[I]) -> Self {
+        let n = nodes.len();
+        Self {
+            nodes: nodes.to_vec(),
+            positions: Self::positions(nodes),
+            distances: DMatrix::from_element(n, n, None),
+            prev: vec![HashMap::new(); n],
+        }
+    }
+
+    /// The node set, in the order of the matrix rows and columns.
+    pub fn nodes(&self) -> &[I] {
+        &self.nodes
+    }
+
+    /// Best cost of every pair, `None` for unreachable pairs.
+    pub fn matrix(&self) -> &DMatrix<Option<C>> {
+        &self.distances
+    }
+
+    /// Best cost from `from` to `to`, or `None` if `to` can not be
+    /// reached or either is not in the node set.
+    pub fn distance(&self, from: &I, to: &I) -> Option<C> {
+        self.distances[(*self.positions.get(from)?, *self.positions.get(to)?)]
+    }
+
+    /// Best path from `from` to `to`, both included, along with any
+    /// node outside the set it goes through.
+    pub fn path(&self, from: &I, to: &I) -> Result<Vec<I>, PathError<I>> {
+        let (Some(&start), Some(&end)) = (self.positions.get(from), self.positions.get(to)) else {
+            return Err(PathError::Unreachable(*to));
+        };
+        if self.distances[(start, end)].is_none() {
+            return Err(PathError::Unreachable(*to));
+        }
+
+        let mut path = vec![*to];
+        let mut cur = *to;
+        while cur != *from {
+            cur = *self.prev[start]
+                .get(&cur)
+                .ok_or(PathError::Unreachable(*to))?;
+            path.push(cur);
+        }
+        path.reverse();
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dijkstra::AdjacencyList, test_graphs::Edges};
+
+    fn edges() -> Edges {
+        Edges(vec![
+            (0, 4, 1),
+            (0, 1, 2),
+            (2, 2, 1),
+            (1, 1, 3),
+            (2, 5, 3),
+            (3, 3, 4),
+            (4, 1, 0),
+            // 9 is outside the node set
+            (0, 1, 9),
+            (9, 1, 4),
+        ])
+    }
+
+    fn path_cost(edges: &Edges, path: &[i32]) -> i32 {
+        path.windows(2)
+            .map(|step| {
+                let moves = edges.get_adjacent(&step[0]);
+                moves
+                    .iter()
+                    .filter(|(_, to)| *to == step[1])
+                    .map(|(cost, _)| *cost)
+                    .min()
+                    .unwrap()
+            })
+            .sum()
+    }
+
+    fn check_paths(edges: &Edges, all_pairs: &AllPairs<i32, i32>) {
+        for from in all_pairs.nodes() {
+            for to in all_pairs.nodes() {
+                let Ok(path) = all_pairs.path(from, to) else {
+                    assert_eq!(all_pairs.distance(from, to), None);
+                    continue;
+                };
+                assert_eq!(path.first(), Some(from));
+                assert_eq!(path.last(), Some(to));
+                assert_eq!(Some(path_cost(edges, &path)), all_pairs.distance(from, to));
+            }
+        }
+    }
+
+    #[test]
+    fn repeated_dijkstra_goes_through_other_nodes() {
+        let edges = edges();
+        let all_pairs = repeated_dijkstra(&edges, &[0, 1, 2, 3, 4, 5]);
+
+        assert_eq!(all_pairs.distance(&0, &4), Some(2));
+        assert_eq!(all_pairs.path(&0, &4), Ok(vec![0, 9, 4]));
+        assert_eq!(all_pairs.distance(&4, &3), Some(5));
+        assert_eq!(all_pairs.path(&4, &3), Ok(vec![4, 0, 2, 1, 3]));
+        assert_eq!(all_pairs.path(&3, &3), Ok(vec![3]));
+        assert_eq!(all_pairs.distance(&0, &5), None);
+        assert_eq!(all_pairs.path(&0, &5), Err(PathError::Unreachable(5)));
+        assert_eq!(all_pairs.distance(&0, &9), None);
+        assert_eq!(all_pairs.path(&0, &9), Err(PathError::Unreachable(9)));
+        check_paths(&edges, &all_pairs);
+    }
+
+    #[test]
+    fn floyd_warshall_matches_repeated_dijkstra() {
+        // closed under adjacency, so nothing is missed
+        let edges = edges();
+        let nodes = [0, 1, 2, 3, 4, 5, 9];
+        let dense = floyd_warshall(&edges, &nodes);
+        let sparse = repeated_dijkstra(&edges, &nodes);
+        assert_eq!(dense.matrix(), sparse.matrix());
+        for all_pairs in [&dense, &sparse] {
+            assert_eq!(all_pairs.path(&0, &4), Ok(vec![0, 9, 4]));
+            check_paths(&edges, all_pairs);
+        }
+
+        // without 9 only the moves inside the set are seen
+        let dense = floyd_warshall(&edges, &[0, 1, 2, 3, 4]);
+        assert_eq!(dense.distance(&0, &4), Some(7));
+        assert_eq!(dense.path(&0, &4), Ok(vec![0, 2, 1, 3, 4]));
+    }
+
+    #[test]
+    fn floyd_warshall_negative_costs() {
+        let edges = Edges(vec![(0, 4, 1), (0, 5, 2), (1, -3, 2), (2, 2, 3)]);
+        let all_pairs = floyd_warshall(&edges, &[0, 1, 2, 3]);
+        assert_eq!(all_pairs.distance(&0, &3), Some(3));
+        assert_eq!(all_pairs.path(&0, &3), Ok(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn density_heuristic() {
+        // complete graph
+        assert!(prefers_floyd_warshall(100, 100 * 99));
+        // grid like
+        assert!(!prefers_floyd_warshall(10_000, 40_000));
+
+        // dense, but 9 is left out of the set
+        let edges = edges();
+        let nodes = [0, 1, 2, 3, 4];
+        assert_eq!(all_pairs(&edges, &nodes).distance(&0, &4), Some(2));
+        assert_eq!(
+            all_pairs(&edges, &nodes).matrix(),
+            repeated_dijkstra(&edges, &nodes).matrix()
+        );
+
+        let nodes = [0, 1, 2, 3, 4, 9];
+        assert_eq!(
+            all_pairs(&edges, &nodes).matrix(),
+            floyd_warshall(&edges, &nodes).matrix()
+        );
+    }
+}
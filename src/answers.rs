@@ -0,0 +1,160 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+};
+
+use anyhow::{Context, Error};
+use pest::Parser;
+use pest_derive::Parser;
+
+/// The small part of TOML the answers files use: comments, one table
+/// per input and `partN = "answer"` keys.
+#[derive(Parser)]
+#[grammar_inline = r##"
+    space = _{ " " | "\t" }
+    comment = _{ "#" ~ (!NEWLINE ~ ANY)* }
+    name = { (ASCII_ALPHANUMERIC | "_" | "-" | ".")+ }
+    table = { "[" ~ space* ~ name ~ space* ~ "]" }
+    part = { ASCII_DIGIT+ }
+    quoted = { (!("\"" | NEWLINE) ~ ANY)* }
+    bare = { "-"? ~ ASCII_DIGIT+ }
+    value = _{ "\"" ~ quoted ~ "\"" | bare }
+    answer = { "part" ~ part ~ space* ~ "=" ~ space* ~ value }
+    line = _{ space* ~ (table | answer)? ~ space* ~ comment? }
+    data = { SOI ~ line ~ (NEWLINE ~ line)* ~ EOI }
+"##]
+struct PestParser;
+
+/// Known-good answers of a day, per input name and part, e.g.
+///
+/// ```toml
+/// [day7]
+/// part1 = "1646"
+/// part2 = "32451134474991"
+/// ```
+///
+/// The input name is the file the answers were found for, relative to
+/// the data directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    inputs: BTreeMap<String, BTreeMap<usize, String>>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut answers = Self::default();
+        let mut table = None;
+        let mut data = PestParser::parse(Rule::data, input)?;
+        for pair in data.next().unwrap().into_inner() {
+            match pair.as_rule() {
+                Rule::table => {
+                    let name = pair.into_inner().next().unwrap().as_str();
+                    answers.inputs.entry(name.to_string()).or_default();
+                    table = Some(name);
+                }
+                Rule::answer => {
+                    let (line, _) = pair.line_col();
+                    let name =
+                        table.with_context(|| format!("line {line}: answer outside a table"))?;
+                    let mut pairs = pair.into_inner();
+                    let part = pairs.next().unwrap().as_str().parse()?;
+                    let answer = pairs.next().unwrap().as_str();
+                    answers.insert(name, part, answer);
+                }
+                Rule::EOI => (),
+                _ => unreachable!(),
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Reads an answers file, a missing one has no answers yet.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(input) => Self::parse(&input).with_context(|| format!("parsing {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("reading {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string()).with_context(|| format!("writing {}", path.display()))
+    }
+
+    pub fn get(&self, input: &str, part: usize) -> Option<&str> {
+        self.inputs.get(input)?.get(&part).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, input: &str, part: usize, answer: &str) {
+        self.inputs
+            .entry(input.to_string())
+            .or_default()
+            .insert(part, answer.to_string());
+    }
+
+    /// Names of the inputs with a table, in order.
+    pub fn inputs(&self) -> impl Iterator<Item = &str> {
+        self.inputs.keys().map(String::as_str)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (n, (input, parts)) in self.inputs.iter().enumerate() {
+            if n > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[{input}]")?;
+            for (part, answer) in parts {
+                writeln!(f, "part{part} = {answer:?}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tables_and_comments() {
+        let answers = Answers::parse(
+            "# day 7\n[day7]\npart1 = \"1646\"  # splits\npart2 = 32451134474991\n\n[ example ]\npart1 = \"21\"\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get("day7", 1), Some("1646"));
+        assert_eq!(answers.get("day7", 2), Some("32451134474991"));
+        assert_eq!(answers.get("example", 1), Some("21"));
+        assert_eq!(answers.get("example", 2), None);
+        assert_eq!(answers.get("day8", 1), None);
+        assert_eq!(answers.inputs().collect::<Vec<_>>(), ["day7", "example"]);
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut answers = Answers::default();
+        answers.insert("day12", 1, "519");
+        answers.insert("day1", 2, "6907");
+        answers.insert("day1", 1, "1182");
+        let written = answers.to_string();
+        assert_eq!(
+            written,
+            "[day1]\npart1 = \"1182\"\npart2 = \"6907\"\n\n[day12]\npart1 = \"519\"\n"
+        );
+        assert_eq!(Answers::parse(&written).unwrap(), answers);
+        assert_eq!(Answers::parse("").unwrap(), Answers::default());
+    }
+
+    #[test]
+    fn rejects_bad_lines() {
+        assert!(Answers::parse("part1 = \"3\"\n").is_err());
+        assert!(Answers::parse("[day1]\npart = \"3\"\n").is_err());
+        assert!(Answers::parse("[day1]\npart1 = \"3\n").is_err());
+    }
+}
@@ -0,0 +1,349 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt::{self, Debug, Display},
+    hash::Hash,
+    ops::{Add, Sub},
+};
+
+use num::Zero;
+
+use crate::dijkstra::{DijkstraConfig, DijkstraInput, DijkstraMap, DijkstraResult, PrevPathSet};
+
+/// Errors returned by the solvers supporting negative costs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BellmanFordError<I> {
+    /// The indexes of a cycle with a negative total cost, in the
+    /// order the edges are taken.
+    NegativeCycle(Vec<I>),
+}
+
+impl<I: Debug> Display for BellmanFordError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BellmanFordError::NegativeCycle(cycle) => write!(f, "negative cycle {cycle:?}"),
+        }
+    }
+}
+
+impl<I: Debug> Error for BellmanFordError<I> {}
+
+/// Follows the previous indexes back from `x` looking for a cycle,
+/// returning its indexes in edge order if there is one.
+fn find_cycle<I, C>(x: I, best: &HashMap<I, (C, I)>) -> Option<Vec<I>>
+where
+    I: Eq + Hash + Copy,
+{
+    let mut seen = HashSet::new();
+    let mut cur = x;
+    while seen.insert(cur) {
+        let prev = best[&cur].1;
+        // the starting location is its own previous index
+        if prev == cur {
+            return None;
+        }
+        cur = prev;
+    }
+
+    let mut cycle = vec![cur];
+    let mut prev = best[&cur].1;
+    while prev != cur {
+        cycle.push(prev);
+        prev = best[&prev].1;
+    }
+    cycle.reverse();
+    Some(cycle)
+}
+
+/// Single source shortest paths allowing negative costs. Returns the
+/// same mapping as [`DijkstraMap::run`] with the single best previous
+/// index, or the offending cycle if one with a negative total cost is
+/// reachable from the start.
+///
+/// Nodes are discovered through [`DijkstraInput::for_each_adjacent`]
+/// so the graph does not need to be known up front.
+pub fn bellman_ford<T>(
+    dijkstra_input: &T,
+    start: (T::Cost, T::Index),
+) -> Result<DijkstraResult<T, T::Index>, BellmanFordError<T::Index>>
+where
+    T: DijkstraInput,
+    T::Cost: Ord + Add<Output = T::Cost> + Copy,
+    T::Index: Eq + Hash + Copy,
+{
+    let mut best: DijkstraResult<T, T::Index> = HashMap::from([(start.1, (start.0, start.1))]);
+    // number of edges on the best walk found so far to each index
+    let mut lengths = HashMap::from([(start.1, 0)]);
+    let mut queue = VecDeque::from([start.1]);
+    let mut queued = HashSet::from([start.1]);
+
+    while let Some(cur_index) = queue.pop_front() {
+        queued.remove(&cur_index);
+        let cost = best[&cur_index].0;
+        let length = lengths[&cur_index];
+
+        let mut suspect = None;
+        dijkstra_input.for_each_adjacent(&cur_index, |neighbor_cost, neighbor_index| {
+            let alt_cost = cost + neighbor_cost;
+            if best.get(&neighbor_index).is_none_or(|(c, _)| alt_cost < *c) {
+                best.insert(neighbor_index, (alt_cost, cur_index));
+                lengths.insert(neighbor_index, length + 1);
+                // a walk with at least as many edges as there are known
+                // nodes repeats one, which only pays off on a negative cycle
+                if length + 1 >= best.len() {
+                    suspect = Some(neighbor_index);
+                }
+                if queued.insert(neighbor_index) {
+                    queue.push_back(neighbor_index);
+                }
+            }
+        });
+
+        if let Some(cycle) = suspect.and_then(|x| find_cycle(x, &best)) {
+            return Err(BellmanFordError::NegativeCycle(cycle));
+        }
+    }
+
+    Ok(best)
+}
+
+/// Adds a virtual source in front of a graph with a free edge to each
+/// of the given nodes, represented by `None`.
+struct WithSource<'a, T>
+where
+    T: DijkstraInput,
+{
+    dijkstra_input: &'a T,
+    nodes: &'a [T::Index],
+}
+
+impl<T> DijkstraInput for WithSource<'_, T>
+where
+    T: DijkstraInput,
+    T::Cost: Zero,
+    T::Index: Copy,
+{
+    type Cost = T::Cost;
+    type Index = Option<T::Index>;
+
+    fn for_each_adjacent<F>(&self, x: &Self::Index, mut f: F)
+    where
+        F: FnMut(Self::Cost, Self::Index),
+    {
+        match x {
+            None => self.nodes.iter().for_each(|n| f(T::Cost::zero(), Some(*n))),
+            Some(x) => self
+                .dijkstra_input
+                .for_each_adjacent(x, |cost, index| f(cost, Some(index))),
+        }
+    }
+}
+
+/// A graph with its costs shifted by the node potentials so that none
+/// are negative, while keeping the same best paths.
+struct Reweighted<'a, T>
+where
+    T: DijkstraInput,
+{
+    dijkstra_input: &'a T,
+    potentials: &'a HashMap<T::Index, T::Cost>,
+}
+
+impl<T> DijkstraInput for Reweighted<'_, T>
+where
+    T: DijkstraInput,
+    T::Cost: Add<Output = T::Cost> + Sub<Output = T::Cost> + Copy,
+    T::Index: Eq + Hash,
+{
+    type Cost = T::Cost;
+    type Index = T::Index;
+
+    fn for_each_adjacent<F>(&self, x: &Self::Index, mut f: F)
+    where
+        F: FnMut(Self::Cost, Self::Index),
+    {
+        let from = self.potentials[x];
+        self.dijkstra_input.for_each_adjacent(x, |cost, index| {
+            f(cost + from - self.potentials[&index], index)
+        });
+    }
+}
+
+/// Johnson's reweighting. A single Bellman-Ford pass over the given
+/// nodes computes potentials that make every cost non-negative, after
+/// which [`DijkstraMap`] can be run from any of the nodes.
+pub struct Johnson<'a, T>
+where
+    T: DijkstraInput,
+{
+    dijkstra_input: &'a T,
+    potentials: HashMap<T::Index, T::Cost>,
+}
+
+impl<'a, T> Johnson<'a, T>
+where
+    T: DijkstraInput,
+    T::Cost: Ord
+        + Eq
+        + PartialEq
+        + Debug
+        + Zero
+        + Add<Output = T::Cost>
+        + Sub<Output = T::Cost>
+        + Clone
+        + Copy,
+    T::Index: Eq + PartialEq + PartialOrd + Debug + Hash + Clone + Copy,
+    (T::Cost, T::Index): Ord,
+{
+    /// `nodes` are the indexes searches may start from, anything
+    /// reachable from them is covered as well.
+    pub fn new(
+        dijkstra_input: &'a T,
+        nodes: &[T::Index],
+    ) -> Result<Self, BellmanFordError<T::Index>> {
+        let with_source = WithSource {
+            dijkstra_input,
+            nodes,
+        };
+        let best = bellman_ford(&with_source, (T::Cost::zero(), None)).map_err(
+            |BellmanFordError::NegativeCycle(cycle)| {
+                BellmanFordError::NegativeCycle(cycle.into_iter().flatten().collect())
+            },
+        )?;
+        let potentials = best
+            .into_iter()
+            .filter_map(|(idx, (cost, _))| Some((idx?, cost)))
+            .collect();
+
+        Ok(Self {
+            dijkstra_input,
+            potentials,
+        })
+    }
+
+    /// Runs [`DijkstraMap::run`] from `start` on the reweighted graph
+    /// and returns the result with the real costs. `None` if `start`
+    /// is neither one of the nodes given to [`Johnson::new`] nor
+    /// reachable from them, as it then has no potential.
+    pub fn run<U>(&self, start: T::Index) -> Option<DijkstraResult<T, U>>
+    where
+        U: PrevPathSet<T::Index>,
+    {
+        let from = *self.potentials.get(&start)?;
+        let reweighted = Reweighted {
+            dijkstra_input: self.dijkstra_input,
+            potentials: &self.potentials,
+        };
+        let mut dmap = DijkstraMap::<_, U>::new(&reweighted, DijkstraConfig::default());
+        dmap.run((T::Cost::zero(), start));
+
+        let result = dmap
+            .into_visited()
+            .into_iter()
+            .map(|(idx, (cost, prev))| (idx, (cost - from + self.potentials[&idx], prev)))
+            .collect();
+        Some(result)
+    }
+
+    /// Best cost between every pair of `nodes` that are connected.
+    /// Nodes [`Johnson::run`] can't start from are skipped.
+    pub fn all_pairs(&self, nodes: &[T::Index]) -> HashMap<(T::Index, T::Index), T::Cost> {
+        nodes
+            .iter()
+            .filter_map(|from| Some((from, self.run::<T::Index>(*from)?)))
+            .flat_map(|(from, result)| {
+                nodes
+                    .iter()
+                    .filter_map(move |to| Some(((*from, *to), result.get(to)?.0)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_graphs::Edges;
+
+    fn negative_edges() -> Edges {
+        Edges(vec![
+            (0, 4, 1),
+            (0, 5, 2),
+            (1, -3, 2),
+            (2, 2, 3),
+            (1, 6, 3),
+            (3, -1, 4),
+            (4, 3, 1),
+        ])
+    }
+
+    #[test]
+    fn bellman_ford_negative_edges() {
+        let best = bellman_ford(&negative_edges(), (0, 0)).unwrap();
+        assert_eq!(best[&1], (4, 0));
+        assert_eq!(best[&2], (1, 1));
+        assert_eq!(best[&3], (3, 2));
+        assert_eq!(best[&4], (2, 3));
+        assert_eq!(
+            DijkstraMap::<Edges, i32>::extract_path(&0, &4, &best),
+            Ok(vec![0, 1, 2, 3, 4])
+        );
+    }
+
+    #[test]
+    fn bellman_ford_negative_cycle() {
+        let edges = Edges(vec![
+            (0, 1, 1),
+            (1, -2, 2),
+            (2, 1, 3),
+            (3, -1, 1),
+            (3, 1, 4),
+        ]);
+        let Err(BellmanFordError::NegativeCycle(mut cycle)) = bellman_ford(&edges, (0, 0)) else {
+            panic!("expected a negative cycle");
+        };
+        // the cycle may be reported from any of its nodes
+        let first = cycle.iter().position(|x| *x == 1).unwrap();
+        cycle.rotate_left(first);
+        assert_eq!(cycle, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn johnson_matches_bellman_ford() {
+        let edges = negative_edges();
+        let nodes = [0, 1, 2, 3, 4];
+        let johnson = Johnson::new(&edges, &nodes).unwrap();
+        let all_pairs = johnson.all_pairs(&nodes);
+
+        for from in nodes {
+            let expected = bellman_ford(&edges, (0, from)).unwrap();
+            for to in nodes {
+                assert_eq!(
+                    all_pairs.get(&(from, to)),
+                    expected.get(&to).map(|(cost, _)| cost)
+                );
+            }
+            let result = johnson.run::<Vec<i32>>(from).unwrap();
+            assert_eq!(result.len(), expected.len());
+        }
+    }
+
+    #[test]
+    fn johnson_start_outside_nodes() {
+        let edges = negative_edges();
+        // 0 has no move into it, so it is not covered from 2
+        let johnson = Johnson::new(&edges, &[2]).unwrap();
+        assert!(johnson.run::<i32>(0).is_none());
+        assert_eq!(johnson.run::<i32>(1).unwrap()[&4].0, -2);
+        assert_eq!(johnson.all_pairs(&[0, 1]), HashMap::from([((1, 1), 0)]));
+    }
+
+    #[test]
+    fn johnson_negative_cycle() {
+        let edges = Edges(vec![(0, 1, 1), (1, -2, 2), (2, 1, 1)]);
+        assert!(matches!(
+            Johnson::new(&edges, &[0, 1, 2]),
+            Err(BellmanFordError::NegativeCycle(_))
+        ));
+    }
+}
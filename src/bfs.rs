@@ -0,0 +1,215 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Debug,
+    hash::Hash,
+    ops::Add,
+};
+
+use num::{One, Zero};
+
+use crate::dijkstra::{DijkstraInput, DijkstraResult, PrevPathSet};
+
+/// Breadth first search for inputs where every move costs one.
+/// Returns the same mapping as [`crate::dijkstra::DijkstraMap::run`],
+/// including all tied previous indexes when `U` is a `Vec`.
+pub fn bfs<T, U>(dijkstra_input: &T, start: (T::Cost, T::Index)) -> DijkstraResult<T, U>
+where
+    T: DijkstraInput,
+    T::Cost: PartialEq + Debug + One + Add<Output = T::Cost> + Copy,
+    T::Index: Eq + Hash + Copy,
+    U: PrevPathSet<T::Index>,
+{
+    let mut visited: DijkstraResult<T, U> = HashMap::new();
+    visited.insert(start.1, (start.0, U::init(start.1)));
+    let mut queue = VecDeque::from([start.1]);
+
+    while let Some(cur_index) = queue.pop_front() {
+        let alt_cost = visited[&cur_index].0 + T::Cost::one();
+        dijkstra_input.for_each_adjacent(&cur_index, |neighbor_cost, neighbor_index| {
+            debug_assert!(
+                neighbor_cost.is_one(),
+                "bfs only supports a cost of 1, got {neighbor_cost:?}"
+            );
+            // every node of this layer is expanded before the next one,
+            // so ties are collected before anything moves on from them
+            if let Some(val) = visited.get_mut(&neighbor_index) {
+                if val.0 == alt_cost {
+                    val.1.append(cur_index);
+                }
+            } else {
+                visited.insert(neighbor_index, (alt_cost, U::init(cur_index)));
+                queue.push_back(neighbor_index);
+            }
+        });
+    }
+
+    visited
+}
+
+/// Deque based search for inputs where every move costs zero or one,
+/// free moves go to the front and the others to the back. Returns
+/// the same mapping as [`crate::dijkstra::DijkstraMap::run`].
+pub fn zero_one_bfs<T, U>(dijkstra_input: &T, start: (T::Cost, T::Index)) -> DijkstraResult<T, U>
+where
+    T: DijkstraInput,
+    T::Cost: Ord + Debug + Zero + One + Add<Output = T::Cost> + Copy,
+    T::Index: Eq + Hash + Copy,
+    U: PrevPathSet<T::Index>,
+{
+    let mut unvisited_best_paths: DijkstraResult<T, U> = HashMap::new();
+    let mut visited: DijkstraResult<T, U> = HashMap::new();
+    unvisited_best_paths.insert(start.1, (start.0, U::init(start.1)));
+    let mut queue = VecDeque::from([start.1]);
+
+    while let Some(cur_index) = queue.pop_front() {
+        // a node is queued again every time its cost improves, the
+        // older entries are left behind and skipped here
+        let Some((cost, prev_idxs)) = unvisited_best_paths.remove(&cur_index) else {
+            continue;
+        };
+        visited.insert(cur_index, (cost, prev_idxs));
+
+        dijkstra_input.for_each_adjacent(&cur_index, |neighbor_cost, neighbor_index| {
+            let is_free = neighbor_cost.is_zero();
+            debug_assert!(
+                is_free || neighbor_cost.is_one(),
+                "zero_one_bfs only supports costs of 0 and 1, got {neighbor_cost:?}"
+            );
+            let alt_cost = cost + neighbor_cost;
+            // a free move can reach a node of the same cost that was
+            // settled first, which is a tie unless it closes a loop of
+            // free moves
+            if visited.contains_key(&neighbor_index) {
+                if visited[&neighbor_index].0 == alt_cost
+                    && !leads_back(&visited, cur_index, neighbor_index)
+                {
+                    let (_, prev_idxs) = visited.get_mut(&neighbor_index).unwrap();
+                    prev_idxs.append(cur_index);
+                }
+                return;
+            }
+
+            let improved = if let Some(val) = unvisited_best_paths.get_mut(&neighbor_index) {
+                if val.0 > alt_cost {
+                    *val = (alt_cost, U::init(cur_index));
+                    true
+                } else {
+                    if val.0 == alt_cost {
+                        val.1.append(cur_index);
+                    }
+                    false
+                }
+            } else {
+                unvisited_best_paths.insert(neighbor_index, (alt_cost, U::init(cur_index)));
+                true
+            };
+
+            if improved && is_free {
+                queue.push_front(neighbor_index);
+            } else if improved {
+                queue.push_back(neighbor_index);
+            }
+        });
+    }
+
+    visited
+}
+
+/// Whether following the previous indexes back from `from` reaches
+/// `to`, only going through nodes that cost as much as `to`.
+fn leads_back<I, C, U>(visited: &HashMap<I, (C, U)>, from: I, to: I) -> bool
+where
+    I: Eq + Hash + Copy,
+    C: PartialEq,
+    U: PrevPathSet<I>,
+{
+    let cost = &visited[&to].0;
+    let mut seen = HashSet::from([from]);
+    let mut stack = vec![from];
+    while let Some(idx) = stack.pop() {
+        if idx == to {
+            return true;
+        }
+        for prev in visited[&idx].1.indexes() {
+            if visited[prev].0 == *cost && seen.insert(*prev) {
+                stack.push(*prev);
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dijkstra::{DijkstraConfig, DijkstraMap},
+        test_graphs::{Grid, assert_same},
+    };
+
+    /// `0` moves are free, `#` is a wall. No two free cells touch,
+    /// so there are no loops of free moves and every tie is kept
+    /// whatever the expansion order.
+    fn grid() -> Grid {
+        Grid::parse(&[
+            "01010", //
+            "1#101", //
+            "01010", //
+            "10#01", //
+            "01010", //
+        ])
+    }
+
+    /// Same grid where everything but walls costs one.
+    fn unit_grid() -> Grid {
+        Grid::parse(&[
+            "11111", //
+            "1#111", //
+            "11111", //
+            "11#11", //
+            "11111", //
+        ])
+    }
+
+    #[test]
+    fn zero_one_bfs_matches_dijkstra() {
+        let grid = grid();
+        let mut dmap = DijkstraMap::<_, Vec<(i32, i32)>>::new(&grid, DijkstraConfig::default());
+        let expected = dmap.run((0, (0, 0)));
+        let result = zero_one_bfs::<_, Vec<(i32, i32)>>(&grid, (0, (0, 0)));
+
+        assert_same(&result, expected);
+    }
+
+    #[test]
+    fn zero_one_bfs_skips_loops_of_free_moves() {
+        let grid = Grid::parse(&["000", "000"]);
+        let result = zero_one_bfs::<_, Vec<(i32, i32)>>(&grid, (0, (0, 0)));
+        assert_eq!(result.len(), 6);
+        for (idx, (_, prev)) in result.iter().filter(|(idx, _)| **idx != (0, 0)) {
+            for p in prev {
+                assert!(!leads_back(&result, *p, *idx), "{p:?} -> {idx:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn bfs_matches_dijkstra() {
+        let grid = unit_grid();
+        let mut dmap = DijkstraMap::<_, Vec<(i32, i32)>>::new(&grid, DijkstraConfig::default());
+        let expected = dmap.run((0, (0, 0)));
+        let result = bfs::<_, Vec<(i32, i32)>>(&grid, (0, (0, 0)));
+        assert_same(&result, expected);
+        assert_eq!(
+            DijkstraMap::<Grid, Vec<(i32, i32)>>::count_all_paths::<u64>(&(0, 0), &(4, 4), &result),
+            DijkstraMap::<Grid, Vec<(i32, i32)>>::count_all_paths(&(0, 0), &(4, 4), expected),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "only supports a cost of 1")]
+    #[cfg(debug_assertions)]
+    fn bfs_rejects_other_costs() {
+        bfs::<_, (i32, i32)>(&grid(), (0, (0, 0)));
+    }
+}
@@ -0,0 +1,243 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+    ops::Add,
+};
+
+use num::Zero;
+
+use crate::dijkstra::DijkstraInput;
+
+/// Implement this on top of [`DijkstraInput`] to search from both
+/// ends at once. Types that would rather return a list of nodes can
+/// implement [`ReverseAdjacencyList`] instead.
+pub trait ReversibleInput: DijkstraInput {
+    /// Calls `f` with the cost and index of every node with a move
+    /// into `x`. For graphs where every move can be undone at the
+    /// same cost this is the same as `for_each_adjacent`.
+    fn for_each_reverse_adjacent<F>(&self, x: &Self::Index, f: F)
+    where
+        F: FnMut(Self::Cost, Self::Index);
+}
+
+/// Simpler way to implement [`ReversibleInput`], at the price of a
+/// list allocated for every node the backward search expands.
+pub trait ReverseAdjacencyList: DijkstraInput {
+    /// Must return every node with a move *into* `x`, along with the
+    /// cost of that move.
+    fn get_reverse_adjacent(&self, x: &Self::Index) -> Vec<(Self::Cost, Self::Index)>;
+}
+
+impl<T: ReverseAdjacencyList> ReversibleInput for T {
+    fn for_each_reverse_adjacent<F>(&self, x: &Self::Index, mut f: F)
+    where
+        F: FnMut(Self::Cost, Self::Index),
+    {
+        for (cost, index) in self.get_reverse_adjacent(x) {
+            f(cost, index);
+        }
+    }
+}
+
+/// Best path found by [`bidirectional_dijkstra`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeetingPath<C, I> {
+    /// The node where the forward and backward searches met.
+    pub meeting: I,
+    /// Total cost from start to goal.
+    pub cost: C,
+    /// Path from start to goal, both included.
+    pub path: Vec<I>,
+}
+
+/// One direction of the search.
+struct Frontier<C, I> {
+    best: HashMap<I, (C, I)>,
+    settled: HashSet<I>,
+    unvisited: BinaryHeap<Reverse<(C, I)>>,
+}
+
+impl<C, I> Frontier<C, I>
+where
+    C: Ord + Copy,
+    I: Eq + Hash + Copy,
+    (C, I): Ord,
+{
+    fn new(start: (C, I)) -> Self {
+        Self {
+            best: HashMap::from([(start.1, (start.0, start.1))]),
+            settled: HashSet::new(),
+            unvisited: BinaryHeap::from([Reverse(start)]),
+        }
+    }
+
+    /// Cost of the next node to settle, dropping stale heap entries.
+    fn peek(&mut self) -> Option<C> {
+        while let Some(Reverse((cost, idx))) = self.unvisited.peek() {
+            if !self.settled.contains(idx) {
+                return Some(*cost);
+            }
+            self.unvisited.pop();
+        }
+        None
+    }
+
+    fn relax(&mut self, cost: C, idx: I, prev: I) {
+        if self.settled.contains(&idx) {
+            return;
+        }
+        if self.best.get(&idx).is_none_or(|(c, _)| cost < *c) {
+            self.best.insert(idx, (cost, prev));
+            self.unvisited.push(Reverse((cost, idx)));
+        }
+    }
+
+    /// Path from this direction's start to `x`, starting with `x`.
+    fn path_to(&self, x: I) -> Vec<I> {
+        let mut path = vec![x];
+        let mut cur = x;
+        loop {
+            let prev = self.best[&cur].1;
+            if prev == cur {
+                return path;
+            }
+            path.push(prev);
+            cur = prev;
+        }
+    }
+}
+
+/// Dijkstra run from `start` along [`DijkstraInput::for_each_adjacent`]
+/// and from `goal` along [`ReversibleInput::for_each_reverse_adjacent`]
+/// at the same time, stopping once the two can no longer find anything
+/// better than the best meeting point. Returns `None` if the goal
+/// can not be reached.
+pub fn bidirectional_dijkstra<T>(
+    dijkstra_input: &T,
+    start: (T::Cost, T::Index),
+    goal: T::Index,
+) -> Option<MeetingPath<T::Cost, T::Index>>
+where
+    T: ReversibleInput,
+    T::Cost: Ord + Zero + Add<Output = T::Cost> + Copy,
+    T::Index: Eq + Hash + Copy,
+    (T::Cost, T::Index): Ord,
+{
+    let mut forward = Frontier::new(start);
+    let mut backward = Frontier::new((T::Cost::zero(), goal));
+    let mut meeting = (start.1 == goal).then_some(start);
+
+    while let (Some(forward_cost), Some(backward_cost)) = (forward.peek(), backward.peek()) {
+        // anything found from here on costs at least this much
+        if meeting.is_some_and(|(cost, _)| forward_cost + backward_cost >= cost) {
+            break;
+        }
+
+        let go_forward = forward_cost <= backward_cost;
+        let (this, other) = if go_forward {
+            (&mut forward, &backward)
+        } else {
+            (&mut backward, &forward)
+        };
+
+        let Reverse((cost, cur_index)) = this.unvisited.pop().unwrap();
+        this.settled.insert(cur_index);
+
+        let visit = |neighbor_cost, neighbor_index| {
+            this.relax(cost + neighbor_cost, neighbor_index, cur_index);
+
+            // the move may join up with the other direction
+            if let Some((other_cost, _)) = other.best.get(&neighbor_index) {
+                let total = this.best[&neighbor_index].0 + *other_cost;
+                if meeting.is_none_or(|(cost, _)| total < cost) {
+                    meeting = Some((total, neighbor_index));
+                }
+            }
+        };
+        if go_forward {
+            dijkstra_input.for_each_adjacent(&cur_index, visit);
+        } else {
+            dijkstra_input.for_each_reverse_adjacent(&cur_index, visit);
+        }
+    }
+
+    let (cost, meeting) = meeting?;
+    let mut path = forward.path_to(meeting);
+    path.reverse();
+    path.extend(backward.path_to(meeting).into_iter().skip(1));
+
+    Some(MeetingPath {
+        meeting,
+        cost,
+        path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dijkstra::{DijkstraConfig, DijkstraMap},
+        test_graphs::{Edges, Grid},
+    };
+
+    fn grid() -> Grid {
+        Grid::parse(&[
+            "131291", //
+            "151911", //
+            "421#17", //
+            "718#11", //
+            "111912", //
+        ])
+    }
+
+    #[test]
+    fn bidirectional_matches_dijkstra() {
+        let grid = grid();
+        let mut dmap = DijkstraMap::<_, (i32, i32)>::new(&grid, DijkstraConfig::default());
+        let expected = dmap.run((0, (0, 0)));
+
+        for (goal, (cost, _)) in expected.iter() {
+            let found = bidirectional_dijkstra(&grid, (0, (0, 0)), *goal).unwrap();
+            assert_eq!(found.cost, *cost, "{goal:?}");
+            assert_eq!(found.path.first(), Some(&(0, 0)));
+            assert_eq!(found.path.last(), Some(goal));
+            assert!(found.path.contains(&found.meeting));
+
+            // the stitched path must add up to the reported cost
+            let path_cost: i32 = found.path[1..].iter().map(|x| grid.cost(*x).unwrap()).sum();
+            assert_eq!(path_cost, *cost);
+        }
+    }
+
+    #[test]
+    fn reverse_adjacency_list_feeds_the_search() {
+        let edges = Edges(vec![(0, 1, 1), (2, 3, 1), (1, 5, 2)]);
+        let mut reverse = vec![];
+        edges.for_each_reverse_adjacent(&1, |cost, index| reverse.push((cost, index)));
+        assert_eq!(reverse, vec![(1, 0), (3, 2)]);
+
+        let found = bidirectional_dijkstra(&edges, (0, 0), 2).unwrap();
+        assert_eq!(found.cost, 6);
+        assert_eq!(found.path, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn bidirectional_start_is_goal() {
+        let found = bidirectional_dijkstra(&grid(), (0, (1, 1)), (1, 1)).unwrap();
+        assert_eq!(
+            found,
+            MeetingPath {
+                meeting: (1, 1),
+                cost: 0,
+                path: vec![(1, 1)],
+            }
+        );
+    }
+
+    #[test]
+    fn bidirectional_unreachable() {
+        assert_eq!(bidirectional_dijkstra(&grid(), (0, (0, 0)), (2, 3)), None);
+    }
+}
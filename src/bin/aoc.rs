@@ -0,0 +1,428 @@
+//! Runs the solutions registered in `aoc2025::days`.
+//!
+//! aoc run <day> [<part>] [--input <file>]
+//! aoc run --all [--data <dir>]
+//! aoc verify [<day>] [--data <dir>] [--answers <dir>] [--record]
+//! aoc bench [<day> [<part>]] [--data <dir>] [--iterations <n>] [--report <file>]
+//!
+//! The input defaults to `data/day<day>`. Each day's input is parsed
+//! once for all the parts that are run.
+//!
+//! `verify` checks every answer against `answers/day<day>.toml`, see
+//! [`Answers`], and fails if any of them changed. With `--record` the
+//! answers not stored yet are written there.
+//!
+//! `bench` times parsing and each part separately, `--iterations` times
+//! each (10 by default), and prints their min, median and max. With
+//! `--report` the timings are also written as JSON or CSV, depending on
+//! the file's extension, to compare between commits. Every run is
+//! timed, there is no warmup, but naming the day matters for slow ones:
+//! a single run of day 10 part 2 takes minutes.
+
+use std::{env, fs, path::Path, time::Duration};
+
+use anyhow::{Context, Error, anyhow, bail};
+use aoc2025::{
+    answers::Answers,
+    days::{self, DAYS, Day},
+    timing::{self, Timing},
+};
+
+const USAGE: &str = "\
+usage: aoc run <day> [<part>] [--input <file>]
+       aoc run --all [--data <dir>]
+       aoc verify [<day>] [--data <dir>] [--answers <dir>] [--record]
+       aoc bench [<day> [<part>]] [--data <dir>] [--iterations <n>] [--report <file>]";
+
+const ITERATIONS: usize = 10;
+
+#[derive(Debug)]
+enum Command {
+    Run {
+        day: usize,
+        parts: Vec<usize>,
+        input: Option<String>,
+    },
+    RunAll {
+        data: String,
+    },
+    Verify {
+        day: Option<usize>,
+        data: String,
+        answers: String,
+        record: bool,
+    },
+    Bench {
+        day: Option<usize>,
+        parts: Vec<usize>,
+        data: String,
+        iterations: usize,
+        report: Option<String>,
+    },
+}
+
+/// Flags and numbers given after the command.
+#[derive(Debug, Default)]
+struct Args {
+    all: bool,
+    record: bool,
+    input: Option<String>,
+    data: Option<String>,
+    answers: Option<String>,
+    iterations: Option<usize>,
+    report: Option<String>,
+    positional: Vec<usize>,
+}
+
+impl Args {
+    fn parse(args: &[String]) -> Result<Self, Error> {
+        let mut parsed = Self::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => parsed.all = true,
+                "--record" => parsed.record = true,
+                "--input" => {
+                    parsed.input = Some(args.next().context("--input needs a file")?.clone())
+                }
+                "--data" => {
+                    parsed.data = Some(args.next().context("--data needs a directory")?.clone())
+                }
+                "--answers" => {
+                    parsed.answers =
+                        Some(args.next().context("--answers needs a directory")?.clone())
+                }
+                "--iterations" => {
+                    let n = args.next().context("--iterations needs a number")?;
+                    let n = n
+                        .parse::<usize>()
+                        .with_context(|| format!("bad number {n}"))?;
+                    if n == 0 {
+                        bail!("--iterations must be at least 1");
+                    }
+                    parsed.iterations = Some(n)
+                }
+                "--report" => {
+                    parsed.report = Some(args.next().context("--report needs a file")?.clone())
+                }
+                flag if flag.starts_with("--") => bail!("unknown flag {flag}"),
+                _ => parsed.positional.push(
+                    arg.parse::<usize>()
+                        .with_context(|| format!("bad number {arg}"))?,
+                ),
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn data(&mut self) -> String {
+        self.data.take().unwrap_or_else(|| "data".to_string())
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, Error> {
+    let Some((command, args)) = args.split_first() else {
+        bail!("no command");
+    };
+    let mut args = Args::parse(args)?;
+    if command != "bench" && (args.iterations.is_some() || args.report.is_some()) {
+        bail!("--iterations and --report only go with bench");
+    }
+    match command.as_str() {
+        "run" => {
+            if args.record || args.answers.is_some() {
+                bail!("--record and --answers only go with verify");
+            }
+            if args.all {
+                if !args.positional.is_empty() || args.input.is_some() {
+                    bail!("--all runs every day and part");
+                }
+                return Ok(Command::RunAll { data: args.data() });
+            }
+            if args.data.is_some() {
+                bail!("--data only goes with --all");
+            }
+            match args.positional[..] {
+                [day] => Ok(Command::Run {
+                    day,
+                    parts: vec![],
+                    input: args.input,
+                }),
+                [day, part] => Ok(Command::Run {
+                    day,
+                    parts: vec![part],
+                    input: args.input,
+                }),
+                _ => bail!("expected a day and optionally a part"),
+            }
+        }
+        "verify" => {
+            if args.all || args.input.is_some() {
+                bail!("verify reads every input from --data");
+            }
+            let day = match args.positional[..] {
+                [] => None,
+                [day] => Some(day),
+                _ => bail!("expected at most a day"),
+            };
+            Ok(Command::Verify {
+                day,
+                data: args.data(),
+                answers: args.answers.unwrap_or_else(|| "answers".to_string()),
+                record: args.record,
+            })
+        }
+        "bench" => {
+            if args.all || args.record || args.input.is_some() || args.answers.is_some() {
+                bail!("bench reads every input from --data");
+            }
+            let (day, parts) = match args.positional[..] {
+                [] => (None, vec![]),
+                [day] => (Some(day), vec![]),
+                [day, part] => (Some(day), vec![part]),
+                _ => bail!("expected at most a day and a part"),
+            };
+            Ok(Command::Bench {
+                day,
+                parts,
+                data: args.data(),
+                iterations: args.iterations.unwrap_or(ITERATIONS),
+                report: args.report,
+            })
+        }
+        _ => bail!("unknown command {command}"),
+    }
+}
+
+fn read_input(path: &str) -> Result<String, Error> {
+    fs::read_to_string(path).with_context(|| format!("reading {path}"))
+}
+
+/// Runs `parts` of `day`, or all of them if empty, printing one answer
+/// per line.
+fn run(day: &Day, parts: &[usize], input: &str, labelled: bool) -> Result<(), Error> {
+    let parts = if parts.is_empty() {
+        day.all_parts()
+    } else {
+        parts.to_vec()
+    };
+    let answers = day.solve(input, &parts)?;
+    for (part, answer) in parts.iter().zip(answers) {
+        if labelled {
+            println!("day {:>2} part {part}: {answer}", day.day);
+        } else {
+            println!("{answer}");
+        }
+    }
+    Ok(())
+}
+
+/// Outcome of checking one part on one input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail,
+    Error,
+    Missing,
+    Recorded,
+    NoInput,
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Error => "ERROR",
+            Status::Missing => "missing",
+            Status::Recorded => "recorded",
+            Status::NoInput => "no input",
+        }
+    }
+}
+
+fn print_row(day: &str, part: &str, input: &str, expected: &str, got: &str, status: &str) {
+    println!("{day:>3}  {part:>4}  {input:<10}  {expected:>18}  {got:>18}  {status}");
+}
+
+/// Checks every part of `day` on each input named in its answers file
+/// and on `day<day>`, returning the status of each.
+fn verify_day(
+    day: &Day,
+    data: &str,
+    answers_dir: &str,
+    record: bool,
+) -> Result<Vec<Status>, Error> {
+    let path = Path::new(answers_dir).join(format!("day{}.toml", day.day));
+    let mut answers = Answers::load(&path)?;
+    let default_input = format!("day{}", day.day);
+    let mut inputs = vec![default_input.clone()];
+    inputs.extend(
+        answers
+            .inputs()
+            .filter(|x| *x != default_input)
+            .map(String::from),
+    );
+
+    let mut statuses = vec![];
+    let mut recorded = false;
+    for input in inputs {
+        let parts = day.all_parts();
+        let (got, error) = match fs::read_to_string(Path::new(data).join(&input)) {
+            Err(_) => (None, None),
+            Ok(text) => match day.solve(&text, &parts) {
+                Ok(got) => (Some(got), None),
+                Err(e) => (None, Some(e.to_string())),
+            },
+        };
+        for (n, part) in parts.iter().enumerate() {
+            let expected = answers.get(&input, *part).map(String::from);
+            let got = got.as_ref().map(|got| got[n].as_str());
+            let status = match (&expected, got) {
+                _ if error.is_some() => Status::Error,
+                (_, None) => Status::NoInput,
+                (Some(expected), Some(got)) if expected == got => Status::Pass,
+                (Some(_), Some(_)) => Status::Fail,
+                (None, Some(got)) if record => {
+                    answers.insert(&input, *part, got);
+                    recorded = true;
+                    Status::Recorded
+                }
+                (None, Some(_)) => Status::Missing,
+            };
+            let got = error.as_deref().or(got).unwrap_or("-");
+            print_row(
+                &day.day.to_string(),
+                &part.to_string(),
+                &input,
+                expected.as_deref().unwrap_or("-"),
+                got,
+                status.label(),
+            );
+            statuses.push(status);
+        }
+    }
+
+    if recorded {
+        answers.save(&path)?;
+    }
+    Ok(statuses)
+}
+
+fn verify(day: Option<usize>, data: &str, answers: &str, record: bool) -> Result<(), Error> {
+    let days = match day {
+        Some(day) => vec![days::find(day).with_context(|| format!("no day {day}"))?],
+        None => DAYS.iter().collect(),
+    };
+
+    print_row("day", "part", "input", "expected", "got", "status");
+    let mut statuses = vec![];
+    for day in days {
+        statuses.extend(verify_day(day, data, answers, record)?);
+    }
+
+    let count = |status| statuses.iter().filter(|x| **x == status).count();
+    let failed = count(Status::Fail) + count(Status::Error);
+    println!(
+        "\n{} passed, {failed} failed, {} missing, {} recorded, {} without input",
+        count(Status::Pass),
+        count(Status::Missing),
+        count(Status::Recorded),
+        count(Status::NoInput),
+    );
+    if failed > 0 {
+        bail!("{failed} of {} answers do not match", statuses.len());
+    }
+    Ok(())
+}
+
+fn print_timing(day: &str, step: &str, iterations: &str, min: &str, median: &str, max: &str) {
+    println!("{day:>3}  {step:<5}  {iterations:>10}  {min:>12}  {median:>12}  {max:>12}");
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.3?}")
+}
+
+/// Times `parts` of `day`, or of every day if none, on the inputs in
+/// `data`, and writes the timings to `report` if given.
+fn bench(
+    day: Option<usize>,
+    parts: &[usize],
+    data: &str,
+    iterations: usize,
+    report: Option<&str>,
+) -> Result<(), Error> {
+    let days = match day {
+        Some(day) => vec![days::find(day).with_context(|| format!("no day {day}"))?],
+        None => DAYS.iter().collect(),
+    };
+    // check the report can be written before spending time on it
+    let to_report: Option<fn(&[Timing]) -> String> = match report {
+        None => None,
+        Some(path) if path.ends_with(".json") => Some(timing::to_json),
+        Some(path) if path.ends_with(".csv") => Some(timing::to_csv),
+        Some(path) => bail!("{path}: the report must be a .json or .csv file"),
+    };
+
+    print_timing("day", "step", "iterations", "min", "median", "max");
+    let mut timings = vec![];
+    for day in days {
+        let parts = if parts.is_empty() {
+            day.all_parts()
+        } else {
+            parts.to_vec()
+        };
+        let input = read_input(&format!("{data}/day{}", day.day))?;
+        for timing in day.time(&input, &parts, iterations)? {
+            let stats = timing.stats;
+            print_timing(
+                &timing.day.to_string(),
+                &timing.step.to_string(),
+                &stats.iterations.to_string(),
+                &format_duration(stats.min),
+                &format_duration(stats.median),
+                &format_duration(stats.max),
+            );
+            timings.push(timing);
+        }
+    }
+
+    if let (Some(path), Some(to_report)) = (report, to_report) {
+        fs::write(path, to_report(&timings)).with_context(|| format!("writing {path}"))?;
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Error> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let command = parse_args(&args).map_err(|e| anyhow!("{e}\n{USAGE}"))?;
+    match command {
+        Command::Run { day, parts, input } => {
+            let day = days::find(day).with_context(|| format!("no day {day}"))?;
+            let input = input.unwrap_or_else(|| format!("data/day{}", day.day));
+            let labelled = parts.len() != 1;
+            run(day, &parts, &read_input(&input)?, labelled)?;
+        }
+        Command::RunAll { data } => {
+            for day in DAYS.iter() {
+                let input = read_input(&format!("{data}/day{}", day.day))?;
+                run(day, &[], &input, true)?;
+            }
+        }
+        Command::Verify {
+            day,
+            data,
+            answers,
+            record,
+        } => verify(day, &data, &answers, record)?,
+        Command::Bench {
+            day,
+            parts,
+            data,
+            iterations,
+            report,
+        } => bench(day, &parts, &data, iterations, report.as_deref())?,
+    }
+    Ok(())
+}
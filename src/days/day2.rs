@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+
+use anyhow::Error;
+use itertools::{FoldWhile, Itertools};
+use pest::Parser;
+use pest_derive::Parser;
+
+use crate::days::{Answer, Solution};
+
+#[derive(Parser)]
+#[grammar_inline = r#"
+    begin = { ASCII_DIGIT+ }
+    end = { ASCII_DIGIT+ }
+    range = { begin ~ "-" ~ end }
+    data = { SOI ~ range ~ ("," ~ range)* ~ WHITE_SPACE* ~ EOI}
+"#]
+struct PestParser;
+
+#[derive(Debug)]
+struct Range(usize, usize);
+
+#[derive(Debug)]
+pub struct Data {
+    ranges: Vec<Range>,
+}
+
+impl Data {
+    fn in_range(&self, val: usize) -> bool {
+        for range in self.ranges.iter() {
+            if val >= range.0 && val <= range.1 {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn out_of_range(&self, val: usize) -> bool {
+        self.ranges.iter().all(|range| val > range.1)
+    }
+
+    fn max_digits(&self) -> usize {
+        let max = self.ranges.iter().map(|r| r.1).max().unwrap();
+        max.to_string().len()
+    }
+}
+
+struct State {
+    val: usize,
+    replicates: usize,
+}
+
+impl State {
+    fn to_invalid(&self) -> Option<usize> {
+        let mut s = String::new();
+        let v = self.val.to_string();
+        for _ in 0..self.replicates {
+            s.push_str(&v);
+        }
+        s.parse().ok()
+    }
+}
+
+impl Iterator for State {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.to_invalid()?;
+        self.val += 1;
+        Some(v)
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Data;
+
+    fn parse(input: &str) -> Result<Data, Error> {
+        let mut ranges = vec![];
+
+        let mut data = PestParser::parse(Rule::data, input)?;
+        for pair in data.next().unwrap().into_inner() {
+            match pair.as_rule() {
+                Rule::range => {
+                    let mut pairs = pair.into_inner();
+                    let start = pairs.next().unwrap().as_str().parse::<usize>()?;
+                    let end = pairs.next().unwrap().as_str().parse::<usize>()?;
+
+                    ranges.push(Range(start, end));
+                }
+                Rule::EOI => (),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Data { ranges })
+    }
+
+    fn part1(data: &Data) -> Result<Answer, Error> {
+        let mut state = State {
+            val: 1,
+            replicates: 2,
+        };
+
+        let result = state
+            .fold_while(0, |acc, item| {
+                if data.out_of_range(item) {
+                    FoldWhile::Done(acc)
+                } else if data.in_range(item) {
+                    FoldWhile::Continue(acc + item)
+                } else {
+                    FoldWhile::Continue(acc)
+                }
+            })
+            .into_inner();
+
+        Ok(result.into())
+    }
+
+    fn part2(data: &Data) -> Result<Answer, Error> {
+        let max_digits = data.max_digits();
+
+        let mut total = HashSet::new();
+        for replicates in 2..=max_digits {
+            let mut state = State { val: 1, replicates };
+
+            let result = state
+                .fold_while(HashSet::new(), |mut acc, item| {
+                    if data.out_of_range(item) {
+                        FoldWhile::Done(acc)
+                    } else {
+                        if data.in_range(item) {
+                            acc.insert(item);
+                        }
+                        FoldWhile::Continue(acc)
+                    }
+                })
+                .into_inner();
+
+            result.into_iter().for_each(|n| {
+                total.insert(n);
+            });
+        }
+
+        let sum = total.into_iter().sum::<usize>();
+
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::example_tests;
+
+    const EXAMPLE: &str = "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
+";
+
+    example_tests! {
+        Day2;
+        part1: EXAMPLE => 1227775554u64,
+        part2: EXAMPLE => 4174379265u64,
+    }
+}
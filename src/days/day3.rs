@@ -0,0 +1,119 @@
+use anyhow::Error;
+use debug_print::debug_println;
+use pest::Parser;
+use pest_derive::Parser;
+
+use crate::days::{Answer, Solution};
+
+#[derive(Parser)]
+#[grammar_inline = r#"
+    bank = { ASCII_DIGIT+ }
+    data = { SOI ~ bank ~ (WHITE_SPACE+ ~ bank)* ~ WHITE_SPACE* ~ EOI}
+"#]
+struct PestParser;
+
+#[derive(Debug)]
+struct Bank(Vec<u8>);
+
+#[derive(Debug)]
+pub struct Data {
+    banks: Vec<Bank>,
+}
+
+impl Bank {
+    fn find_largest(&self, num_batteries: usize) -> Vec<u8> {
+        let mut result = vec![];
+        let b = &self.0;
+
+        let mut cur_index = 0;
+        for n in 0..num_batteries {
+            let sidx = cur_index;
+            let eidx = b.len() - (num_batteries - n) + 1;
+            let m = b[sidx..eidx]
+                .iter()
+                .enumerate()
+                .rev()
+                .max_by_key(|v| v.1)
+                .unwrap();
+            result.push(*m.1);
+            cur_index += m.0 + 1;
+        }
+
+        result
+    }
+}
+
+impl Data {
+    fn total_joltage(&self, num_batteries: usize) -> Result<u64, Error> {
+        let mut result = 0;
+        for bank in self.banks.iter() {
+            let max = bank.find_largest(num_batteries);
+            let max = max
+                .into_iter()
+                .map(|x| x.to_string())
+                .collect::<String>()
+                .parse::<u64>()?;
+
+            debug_println!("max: {max}");
+
+            result += max;
+        }
+        Ok(result)
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Data;
+
+    fn parse(input: &str) -> Result<Data, Error> {
+        let mut banks = vec![];
+
+        let mut data = PestParser::parse(Rule::data, input)?;
+        for pair in data.next().unwrap().into_inner() {
+            match pair.as_rule() {
+                Rule::bank => {
+                    let bank = pair.as_str();
+                    let nums = bank
+                        .chars()
+                        .map(|c| c.to_string().parse::<u8>())
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let bank = Bank(nums);
+                    banks.push(bank);
+                }
+                Rule::EOI => (),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Data { banks })
+    }
+
+    fn part1(data: &Data) -> Result<Answer, Error> {
+        Ok(data.total_joltage(2)?.into())
+    }
+
+    fn part2(data: &Data) -> Result<Answer, Error> {
+        Ok(data.total_joltage(12)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::example_tests;
+
+    const EXAMPLE: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    example_tests! {
+        Day3;
+        part1: EXAMPLE => 357,
+        part2: EXAMPLE => 3121910778619u64,
+    }
+}
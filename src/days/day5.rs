@@ -0,0 +1,191 @@
+use anyhow::Error;
+use debug_print::debug_println;
+use pest::Parser;
+use pest_derive::Parser;
+
+use crate::days::{Answer, Solution};
+
+#[derive(Parser)]
+#[grammar_inline = r#"
+    num = { ASCII_DIGIT+ }
+    range = { num ~ "-" ~ num }
+    ingredient = { num }
+    data = { SOI ~ range ~ (WHITE_SPACE+ ~ range)* ~ WHITE_SPACE* ~ ingredient ~ (WHITE_SPACE+ ~ ingredient)* ~ WHITE_SPACE* ~ EOI}
+"#]
+struct PestParser;
+
+#[derive(Debug)]
+pub struct Data {
+    ranges: Vec<(u64, u64)>,
+    ingredients: Vec<u64>,
+}
+
+fn in_range(x: u64, ranges: &[(u64, u64)]) -> bool {
+    ranges.iter().any(|&(start, end)| x >= start && x <= end)
+}
+
+fn start_range_trim(x: u64, ranges: &[(u64, u64)]) -> Option<u64> {
+    // if x is in another range, then return value to trim to
+    for &(start, end) in ranges.iter() {
+        if x > start && x <= end {
+            return Some(end + 1);
+        }
+    }
+    None
+}
+
+fn end_range_trim(x: u64, ranges: &[(u64, u64)]) -> Option<u64> {
+    // if x is in another range, then return value to trim to
+    for &(start, end) in ranges.iter() {
+        if x >= start && x < end {
+            return Some(start - 1);
+        }
+    }
+    None
+}
+
+fn full_overlap(x: u64, y: u64, ranges: &[(u64, u64)]) -> bool {
+    let mut found = false;
+    for &(start, end) in ranges.iter() {
+        if x == start && y == end {
+            if found {
+                return true;
+            }
+            found = true;
+        }
+    }
+    false
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Data;
+
+    fn parse(input: &str) -> Result<Data, Error> {
+        let mut ranges = vec![];
+        let mut ingredients = vec![];
+
+        let mut data = PestParser::parse(Rule::data, input)?;
+        for pair in data.next().unwrap().into_inner() {
+            match pair.as_rule() {
+                Rule::range => {
+                    let mut pairs = pair.into_inner();
+                    let start = pairs.next().unwrap().as_str().parse::<u64>()?;
+                    let end = pairs.next().unwrap().as_str().parse::<u64>()?;
+                    ranges.push((start, end));
+                }
+                Rule::ingredient => {
+                    let mut pairs = pair.into_inner();
+                    let ingredient = pairs.next().unwrap().as_str().parse::<u64>()?;
+                    ingredients.push(ingredient);
+                }
+                Rule::EOI => (),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Data {
+            ranges,
+            ingredients,
+        })
+    }
+
+    fn part1(data: &Data) -> Result<Answer, Error> {
+        let mut count = 0;
+        for &ingredient in data.ingredients.iter() {
+            if in_range(ingredient, &data.ranges) {
+                debug_println!("ingredient {ingredient} in range");
+                count += 1;
+            }
+        }
+
+        Ok(count.into())
+    }
+
+    fn part2(data: &Data) -> Result<Answer, Error> {
+        let mut ranges = data.ranges.clone();
+
+        // trim ranges
+        loop {
+            let mut found_overlap = false;
+            let ranges_tmp = ranges.clone();
+            for (start, end) in ranges.iter_mut() {
+                if start > end {
+                    continue;
+                }
+                if let Some(new_start) = start_range_trim(*start, &ranges_tmp) {
+                    debug_println!(
+                        "found start overlap with range: {:?}, new range: {:?}",
+                        (*start, *end),
+                        (new_start, *end)
+                    );
+                    *start = new_start;
+                    found_overlap = true;
+                    break;
+                }
+                if let Some(new_end) = end_range_trim(*end, &ranges_tmp) {
+                    debug_println!(
+                        "found end overlap with range: {:?}, new range: {:?}",
+                        (*start, *end),
+                        (*start, new_end)
+                    );
+                    *end = new_end;
+                    found_overlap = true;
+                    break;
+                }
+                if full_overlap(*start, *end, &ranges_tmp) {
+                    let new_start = *end + 1;
+                    debug_println!(
+                        "full overlap with range: {:?}, new range: {:?}",
+                        (*start, *end),
+                        (new_start, *end)
+                    );
+                    *start = new_start;
+                    found_overlap = true;
+                    break;
+                }
+            }
+            if !found_overlap {
+                break;
+            }
+        }
+
+        debug_println!("{ranges:#?}");
+
+        let mut count = 0;
+        for (start, end) in ranges {
+            if end >= start {
+                count += end - start + 1;
+            }
+        }
+
+        Ok(count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::example_tests;
+
+    const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    example_tests! {
+        Day5;
+        part1: EXAMPLE => 3,
+        part2: EXAMPLE => 14,
+    }
+}
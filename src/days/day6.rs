@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use anyhow::Error;
+use debug_print::debug_println;
+use pest::Parser;
+use pest_derive::Parser;
+
+use crate::days::{Answer, Solution};
+
+#[derive(Parser)]
+#[grammar_inline = r#"
+    SPACE = { " " }
+    op = { "*" | "+" }
+    num_row = @{ (ASCII_DIGIT | SPACE)+}
+    op_row = { op ~ (WHITE_SPACE+ ~ op)* ~ WHITE_SPACE+ }
+    data = { SOI ~ (num_row ~ NEWLINE)+ ~ op_row ~ EOI}
+"#]
+struct PestParser;
+
+#[derive(Debug)]
+enum Op {
+    Add,
+    Mult,
+}
+
+/// The rows of digits are kept as they are, part 1 reads them across
+/// and part 2 down the columns.
+#[derive(Debug)]
+pub struct Data {
+    nums: Vec<String>,
+    ops: Vec<Op>,
+}
+
+fn find_breaks(
+    num_rows: usize,
+    num_cols: usize,
+    nums: &HashMap<(usize, usize), Option<char>>,
+) -> Vec<usize> {
+    let mut breaks = vec![];
+    for col in 0..num_cols {
+        if (0..num_rows).all(|row| nums.get(&(col, row)).unwrap().is_none()) {
+            breaks.push(col);
+        }
+    }
+    breaks
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Data;
+
+    fn parse(input: &str) -> Result<Data, Error> {
+        let mut nums = vec![];
+        let mut ops = vec![];
+
+        let mut data = PestParser::parse(Rule::data, input)?;
+        for pair in data.next().unwrap().into_inner() {
+            match pair.as_rule() {
+                Rule::num_row => {
+                    nums.push(pair.as_str().to_string());
+                }
+                Rule::op_row => {
+                    let pairs = pair.into_inner();
+                    ops = pairs
+                        .into_iter()
+                        .map(|v| match v.as_str() {
+                            "*" => Op::Mult,
+                            "+" => Op::Add,
+                            _ => unreachable!(),
+                        })
+                        .collect();
+                }
+                Rule::EOI => (),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Data { nums, ops })
+    }
+
+    fn part1(data: &Data) -> Result<Answer, Error> {
+        let nums = data
+            .nums
+            .iter()
+            .map(|row| {
+                row.split_whitespace()
+                    .map(|v| v.parse::<i32>())
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        debug_println!("{nums:#?}");
+
+        // check lengths
+        nums.iter().fold(None, |acc, row| {
+            if let Some(len) = acc {
+                assert!(row.len() == len);
+                acc
+            } else {
+                Some(row.len())
+            }
+        });
+        assert!(data.ops.len() == nums[0].len());
+
+        let num_values = nums[0].len();
+
+        let mut result = 0;
+        for col in 0..num_values {
+            let mut tmp = match data.ops[col] {
+                Op::Add => 0u64,
+                Op::Mult => 1u64,
+            };
+
+            for row in nums.iter() {
+                match data.ops[col] {
+                    Op::Add => tmp += row[col] as u64,
+                    Op::Mult => tmp *= row[col] as u64,
+                }
+            }
+
+            result += tmp;
+        }
+
+        Ok(result.into())
+    }
+
+    fn part2(data: &Data) -> Result<Answer, Error> {
+        // check lengths
+        data.nums.iter().fold(None, |acc, row| {
+            if let Some(len) = acc {
+                assert!(row.len() == len);
+                acc
+            } else {
+                Some(row.len())
+            }
+        });
+
+        let num_rows = data.nums.len();
+        let num_cols = data.nums[0].len();
+
+        let mut nums = HashMap::new();
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                let pos = (col, row);
+                let val = match data.nums[row].chars().nth(col).unwrap() {
+                    c @ '0'..='9' => Some(c),
+                    ' ' => None,
+                    _ => unreachable!(),
+                };
+                nums.insert(pos, val);
+            }
+        }
+
+        debug_println!("{nums:#?}");
+
+        let breaks = find_breaks(num_rows, num_cols, &nums);
+        assert!(breaks.len() == data.ops.len() - 1);
+        debug_println!("{breaks:#?}");
+
+        let ranges = {
+            let r = breaks.into_iter().fold((0, vec![]), |(start, mut acc), b| {
+                acc.push((start, b));
+                (b + 1, acc)
+            });
+            let mut ranges = r.1;
+            ranges.push((r.0, num_cols));
+            ranges
+        };
+        assert!(ranges.len() == data.ops.len());
+        debug_println!("{ranges:#?}");
+
+        let mut count = 0;
+        for (coln, &(col_start, col_end)) in ranges.iter().enumerate() {
+            let mut col_nums = vec![];
+            for col in col_start..col_end {
+                let mut num_string = String::new();
+                for row in 0..num_rows {
+                    if let Some(c) = nums.get(&(col, row)).unwrap() {
+                        num_string.push(*c);
+                    }
+                }
+                let col_num = num_string.parse::<i32>()?;
+                col_nums.push(col_num);
+                debug_println!("{col_num}");
+            }
+
+            let val = match data.ops[coln] {
+                Op::Add => col_nums.iter().fold(0u64, |acc, n| acc + (*n as u64)),
+                Op::Mult => col_nums.iter().fold(1u64, |acc, n| acc * (*n as u64)),
+            };
+
+            count += val;
+        }
+
+        Ok(count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::example_tests;
+
+    // the trailing spaces line the columns up
+    const EXAMPLE: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
+
+    example_tests! {
+        Day6;
+        part1: EXAMPLE => 4277556,
+        part2: EXAMPLE => 3263827,
+    }
+}
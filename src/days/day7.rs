@@ -0,0 +1,267 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Error;
+use pest::Parser;
+use pest_derive::Parser;
+
+use crate::days::{Answer, Solution};
+
+#[derive(Parser)]
+#[grammar_inline = r#"
+    start = { "S" }
+    splitter = { "^" }
+    empty = { "." }
+    row = { (start | splitter | empty)+ }
+    data = { SOI ~ row ~ (WHITE_SPACE ~ row)* ~ WHITE_SPACE ~ EOI}
+"#]
+struct PestParser;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Pos {
+    Start,
+    Empty,
+    Splitter,
+}
+
+#[derive(Debug)]
+pub struct Data {
+    board: HashMap<(i32, i32), Pos>,
+}
+
+impl Data {
+    fn start_pos(&self) -> (i32, i32) {
+        self.board
+            .iter()
+            .find(|&(_, val)| *val == Pos::Start)
+            .map(|(pos, _)| *pos)
+            .unwrap()
+    }
+}
+
+struct Tachyon {
+    is_active: bool,
+    positions: HashSet<(i32, i32)>,
+    cur_pos: (i32, i32),
+}
+
+struct Tachyons {
+    tachyons: Vec<Tachyon>,
+}
+
+enum TachyonAction {
+    OutOfBounds,
+    SplitAt(i32, i32),
+    MoveTo(i32, i32),
+}
+
+impl Tachyons {
+    fn spawn(&mut self, pos: (i32, i32)) {
+        if !self.any_at_position(&pos) {
+            let mut positions = HashSet::new();
+            positions.insert(pos);
+            self.tachyons.push(Tachyon {
+                is_active: true,
+                cur_pos: pos,
+                positions,
+            });
+        }
+    }
+
+    fn any_at_position(&self, pos: &(i32, i32)) -> bool {
+        self.tachyons.iter().any(|t| t.positions.contains(pos))
+    }
+
+    fn advance(&mut self, board: &HashMap<(i32, i32), Pos>) -> usize {
+        let mut splits = 0;
+        let mut actions = vec![];
+        for (n, tachyon) in self
+            .tachyons
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_active)
+        {
+            let (tcol, trow) = tachyon.cur_pos;
+            let new_pos @ (new_tcol, new_trow) = (tcol, trow + 1);
+
+            if let Some(v) = board.get(&new_pos) {
+                match v {
+                    Pos::Start => unreachable!(),
+                    Pos::Empty => {
+                        actions.push((n, TachyonAction::MoveTo(new_tcol, new_trow)));
+                    }
+                    Pos::Splitter => {
+                        actions.push((n, TachyonAction::SplitAt(new_tcol, new_trow)));
+                        splits += 1;
+                    }
+                }
+            } else {
+                // out of bounds
+                actions.push((n, TachyonAction::OutOfBounds));
+            }
+        }
+
+        for (n, action) in actions {
+            match action {
+                TachyonAction::OutOfBounds => self.tachyons[n].is_active = false,
+                TachyonAction::SplitAt(col, row) => {
+                    self.tachyons[n].is_active = false;
+                    self.spawn((col - 1, row));
+                    self.spawn((col + 1, row));
+                }
+                TachyonAction::MoveTo(col, row) => {
+                    if self.any_at_position(&(col, row)) {
+                        // kill/merge this tachyon
+                        self.tachyons[n].is_active = false;
+                    } else {
+                        self.tachyons[n].cur_pos = (col, row);
+                        self.tachyons[n].positions.insert((col, row));
+                    }
+                }
+            }
+        }
+
+        splits
+    }
+
+    fn any_active(&self) -> bool {
+        self.tachyons.iter().any(|t| t.is_active)
+    }
+}
+
+fn fire_tachyon(
+    (col, mut row): (i32, i32),
+    board: &HashMap<(i32, i32), Pos>,
+    memo: &mut HashMap<(i32, i32), u64>,
+) -> u64 {
+    let new_positions;
+    loop {
+        let obj = board.get(&(col, row));
+        // check if out of bounds
+        let Some(obj) = obj else {
+            return 1;
+        };
+        match obj {
+            Pos::Start => unreachable!(),
+            Pos::Empty => {
+                row += 1;
+            }
+            Pos::Splitter => {
+                new_positions = Some(((col - 1, row), (col + 1, row)));
+                break;
+            }
+        }
+    }
+
+    let left = new_positions.unwrap().0;
+    let right = new_positions.unwrap().1;
+
+    if !memo.contains_key(&left) {
+        let v = fire_tachyon(left, board, memo);
+        memo.insert(left, v);
+    }
+
+    if !memo.contains_key(&right) {
+        let v = fire_tachyon(right, board, memo);
+        memo.insert(right, v);
+    }
+
+    memo.get(&left).unwrap() + memo.get(&right).unwrap()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Data;
+
+    fn parse(input: &str) -> Result<Data, Error> {
+        let mut board = HashMap::new();
+        let mut row = 0;
+        let mut col = 0;
+
+        let mut data = PestParser::parse(Rule::data, input)?;
+        for pair in data.next().unwrap().into_inner() {
+            match pair.as_rule() {
+                Rule::row => {
+                    for pairs in pair.into_inner() {
+                        let spot = match pairs.as_rule() {
+                            Rule::splitter => Pos::Splitter,
+                            Rule::empty => Pos::Empty,
+                            Rule::start => Pos::Start,
+                            _ => unreachable!(),
+                        };
+                        board.insert((col, row), spot);
+                        col += 1;
+                    }
+                    col = 0;
+                    row += 1;
+                }
+                Rule::EOI => (),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Data { board })
+    }
+
+    fn part1(data: &Data) -> Result<Answer, Error> {
+        let mut tachyons = Tachyons { tachyons: vec![] };
+        let (start_col, start_row) = data.start_pos();
+        tachyons.spawn((start_col, start_row + 1));
+
+        let mut total_splits = 0;
+        loop {
+            let splits = tachyons.advance(&data.board);
+
+            total_splits += splits;
+
+            if !tachyons.any_active() {
+                break;
+            }
+        }
+
+        Ok(total_splits.into())
+    }
+
+    fn part2(data: &Data) -> Result<Answer, Error> {
+        let (start_col, start_row) = data.start_pos();
+        let mut memoized_splitter_count = HashMap::new();
+        let count = fire_tachyon(
+            (start_col, start_row + 1),
+            &data.board,
+            &mut memoized_splitter_count,
+        );
+
+        Ok(count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::example_tests;
+
+    const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    example_tests! {
+        Day7;
+        part1: EXAMPLE => 21,
+        part2: EXAMPLE => 40,
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::{Context, Error};
+use debug_print::debug_println;
+use itertools::Itertools;
+use pest::Parser;
+use pest_derive::Parser;
+
+use crate::{
+    days::{Answer, Solution},
+    union_find::UnionFind,
+};
+
+#[derive(Parser)]
+#[grammar_inline = r#"
+    num = { ASCII_DIGIT+ }
+    row = { num ~ "," ~ num ~ "," ~ num }
+    data = { SOI ~ row ~ (WHITE_SPACE+ ~ row)* ~ WHITE_SPACE* ~ EOI}
+"#]
+struct PestParser;
+
+#[derive(Debug)]
+pub struct Data {
+    rows: Vec<(i32, i32, i32)>,
+}
+
+const TOP_N: usize = 1000;
+
+impl Data {
+    /// Every pair of boxes, closest first.
+    fn closest_pairs(&self) -> impl Iterator<Item = (usize, usize)> {
+        self.rows
+            .iter()
+            .enumerate()
+            .combinations(2)
+            .map(|combination| {
+                let (n1, (x1, y1, z1)) = combination[0];
+                let (n2, (x2, y2, z2)) = combination[1];
+
+                let dist = (x1 - x2) as i64 * (x1 - x2) as i64
+                    + (y1 - y2) as i64 * (y1 - y2) as i64
+                    + (z1 - z2) as i64 * (z1 - z2) as i64;
+
+                (n1, n2, dist)
+            })
+            .sorted_by_key(|(_, _, dist)| *dist)
+            .map(|(n1, n2, _)| (n1, n2))
+    }
+}
+
+/// Product of the sizes of the three largest circuits once the
+/// `connections` closest pairs are joined.
+fn largest_circuits(data: &Data, connections: usize) -> usize {
+    let mut clusters = UnionFind::new(data.rows.len());
+    for (n1, n2) in data.closest_pairs().take(connections) {
+        clusters.union(n1, n2);
+    }
+
+    debug_println!("{clusters:#?}");
+
+    clusters
+        .component_sizes()
+        .sorted()
+        .rev()
+        .take(3)
+        .product::<usize>()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Data;
+
+    fn parse(input: &str) -> Result<Data, Error> {
+        let mut rows = vec![];
+
+        let mut data = PestParser::parse(Rule::data, input)?;
+        for pair in data.next().unwrap().into_inner() {
+            match pair.as_rule() {
+                Rule::row => {
+                    let mut pairs = pair.into_inner();
+                    let x = pairs.next().unwrap().as_str().parse()?;
+                    let y = pairs.next().unwrap().as_str().parse()?;
+                    let z = pairs.next().unwrap().as_str().parse()?;
+                    rows.push((x, y, z));
+                }
+                Rule::EOI => (),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Data { rows })
+    }
+
+    fn part1(data: &Data) -> Result<Answer, Error> {
+        Ok(largest_circuits(data, TOP_N).into())
+    }
+
+    fn part2(data: &Data) -> Result<Answer, Error> {
+        let mut clusters = UnionFind::new(data.rows.len());
+        let last_join = clusters
+            .merge_until(data.closest_pairs(), 1)
+            .context("boxes never form one circuit")?;
+
+        let d1 = data.rows[last_join.0];
+        let d2 = data.rows[last_join.1];
+
+        let result = d1.0 as i64 * d2.0 as i64;
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::example_tests;
+
+    const EXAMPLE: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+    example_tests! {
+        Day8;
+        part2: EXAMPLE => 25272,
+    }
+
+    #[test]
+    fn single_box_is_an_error() {
+        let data = Day8::parse("1,2,3\n").unwrap();
+        assert!(Day8::part2(&data).is_err());
+    }
+
+    #[test]
+    fn part1_example() {
+        // the example only makes 10 connections
+        let data = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(largest_circuits(&data, 10), 40);
+    }
+}
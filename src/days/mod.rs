@@ -0,0 +1,307 @@
+use std::{
+    fmt::{self, Display},
+    io,
+};
+
+use anyhow::{Error, bail};
+
+use crate::timing::{self, Step, Timing};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// A day's puzzle: the input is parsed once into `Input`, and both
+/// parts are solved from that.
+pub trait Solution {
+    type Input;
+
+    /// Number of parts, the last day only has one.
+    const PARTS: usize = 2;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error>;
+
+    fn part2(_input: &Self::Input) -> Result<Answer, Error> {
+        bail!("there is no part 2")
+    }
+}
+
+/// The answer to a part, as it is printed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+macro_rules! answer_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(x: $t) -> Self {
+                    Answer(x.to_string())
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(i32, i64, u64, u128, usize, &str, String);
+
+fn solve_part<S: Solution>(data: &S::Input, part: usize) -> Result<Answer, Error> {
+    match part {
+        1 => S::part1(data),
+        2 if S::PARTS == 2 => S::part2(data),
+        _ => bail!("there is no part {part}"),
+    }
+}
+
+/// Parses `input` and solves each of `parts` from the same data.
+fn solve<S: Solution>(input: &str, parts: &[usize]) -> Result<Vec<Answer>, Error> {
+    let data = S::parse(input)?;
+    parts
+        .iter()
+        .map(|part| solve_part::<S>(&data, *part))
+        .collect()
+}
+
+/// Times parsing `input` and then each of `parts`, `iterations` times
+/// each. The first error ends the timing, so no run goes untimed.
+fn time<S: Solution>(
+    day: usize,
+    input: &str,
+    parts: &[usize],
+    iterations: usize,
+) -> Result<Vec<Timing>, Error> {
+    let (stats, data) = timing::try_time(iterations, || S::parse(input))?;
+    let mut timings = vec![Timing {
+        day,
+        step: Step::Parse,
+        stats,
+    }];
+    for &part in parts {
+        let (stats, _) = timing::try_time(iterations, || solve_part::<S>(&data, part))?;
+        timings.push(Timing {
+            day,
+            step: Step::Part(part),
+            stats,
+        });
+    }
+    Ok(timings)
+}
+
+type TimeFn = fn(usize, &str, &[usize], usize) -> Result<Vec<Timing>, Error>;
+
+/// Entry in [`DAYS`].
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: usize,
+    pub parts: usize,
+    solve: fn(&str, &[usize]) -> Result<Vec<Answer>, Error>,
+    time: TimeFn,
+}
+
+impl Day {
+    const fn new<S: Solution>(day: usize) -> Self {
+        Self {
+            day,
+            parts: S::PARTS,
+            solve: solve::<S>,
+            time: time::<S>,
+        }
+    }
+
+    /// Answers to each of `parts` for `input`, which is only parsed
+    /// once.
+    pub fn solve(&self, input: &str, parts: &[usize]) -> Result<Vec<Answer>, Error> {
+        (self.solve)(input, parts)
+    }
+
+    /// Timings of parsing `input` and of each of `parts` over
+    /// `iterations` runs, see [`timing`].
+    pub fn time(
+        &self,
+        input: &str,
+        parts: &[usize],
+        iterations: usize,
+    ) -> Result<Vec<Timing>, Error> {
+        (self.time)(self.day, input, parts, iterations)
+    }
+
+    /// Every part, in order.
+    pub fn all_parts(&self) -> Vec<usize> {
+        (1..=self.parts).collect()
+    }
+}
+
+/// Every day, in order.
+pub static DAYS: [Day; 12] = [
+    Day::new::<day1::Day1>(1),
+    Day::new::<day2::Day2>(2),
+    Day::new::<day3::Day3>(3),
+    Day::new::<day4::Day4>(4),
+    Day::new::<day5::Day5>(5),
+    Day::new::<day6::Day6>(6),
+    Day::new::<day7::Day7>(7),
+    Day::new::<day8::Day8>(8),
+    Day::new::<day9::Day9>(9),
+    Day::new::<day10::Day10>(10),
+    Day::new::<day11::Day11>(11),
+    Day::new::<day12::Day12>(12),
+];
+
+pub fn find(day: usize) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// Body of the `dayNpM` binaries: solves `part` on stdin and prints the
+/// answer.
+pub fn run_stdin<S: Solution>(part: usize) -> Result<(), Error> {
+    let input = io::read_to_string(io::stdin())?;
+    for answer in solve::<S>(&input, &[part])? {
+        println!("{answer}");
+    }
+    Ok(())
+}
+
+/// Solves one part of an example and checks the answer, see
+/// [`example_tests`].
+#[cfg(test)]
+pub(crate) fn check_example<S: Solution>(part: usize, input: &str, expected: Answer) {
+    let answers = solve::<S>(input, &[part]).unwrap();
+    assert_eq!(answers[0], expected);
+}
+
+/// Generates `part1_example` and `part2_example` tests from the
+/// examples of a day's puzzle and their answers, e.g.
+///
+/// ```ignore
+/// example_tests! {
+///     Day7;
+///     part1: EXAMPLE => 21,
+///     part2: EXAMPLE => 40,
+/// }
+/// ```
+///
+/// Attributes such as `#[ignore]` are passed on to the test.
+#[cfg(test)]
+macro_rules! example_tests {
+    ($day:ty; $($(#[$meta:meta])* $part:ident: $input:expr => $answer:expr),* $(,)?) => {
+        $(
+            $crate::days::example_tests!(@test $day, $part, $input, $answer, $(#[$meta])*);
+        )*
+    };
+    (@test $day:ty, part1, $input:expr, $answer:expr, $(#[$meta:meta])*) => {
+        $(#[$meta])*
+        #[test]
+        fn part1_example() {
+            $crate::days::check_example::<$day>(1, $input, $answer.into());
+        }
+    };
+    (@test $day:ty, part2, $input:expr, $answer:expr, $(#[$meta:meta])*) => {
+        $(#[$meta])*
+        #[test]
+        fn part2_example() {
+            $crate::days::check_example::<$day>(2, $input, $answer.into());
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) use example_tests;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_is_in_order() {
+        for (n, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.day, n + 1);
+            assert_eq!(find(n + 1).unwrap().day, n + 1);
+        }
+        assert_eq!(find(12).unwrap().all_parts(), vec![1]);
+        assert_eq!(find(7).unwrap().all_parts(), vec![1, 2]);
+        assert!(find(13).is_none());
+    }
+
+    #[test]
+    fn parses_once_for_both_parts() {
+        let day = find(9).unwrap();
+        let answers = day.solve("7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3\n", &[1, 2]);
+        assert_eq!(answers.unwrap(), vec![Answer::from(50), Answer::from(24)]);
+    }
+
+    #[test]
+    fn times_parse_and_each_part() {
+        let day = find(9).unwrap();
+        let input = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3\n";
+        let timings = day.time(input, &[1, 2], 3).unwrap();
+        let steps = timings.iter().map(|x| x.step).collect::<Vec<_>>();
+        assert_eq!(steps, [Step::Parse, Step::Part(1), Step::Part(2)]);
+        assert!(
+            timings
+                .iter()
+                .all(|x| x.day == 9 && x.stats.iterations == 3)
+        );
+        assert!(find(12).unwrap().time("?\n", &[1], 3).is_err());
+        assert!(day.time(input, &[3], 3).is_err());
+    }
+
+    #[test]
+    fn bad_input_is_an_error() {
+        for day in DAYS.iter() {
+            assert!(day.solve("?\n", &day.all_parts()).is_err());
+        }
+
+        // input that passes the grammar but not the number types
+        let eleven_nines = "9\n".repeat(11) + "+\n";
+        let out_of_range = [
+            (1, "R99999999999999999999\n"),
+            (2, "1-99999999999999999999"),
+            (5, "1-99999999999999999999\n\n1\n"),
+            (6, eleven_nines.as_str()),
+            (8, "3000000000,1,1\n"),
+            (9, "3000000000,1\n"),
+            (9, "1,1\n"),
+            (10, "[.#] (300) {1}\n"),
+            (10, "[.#] (5) {1}\n"),
+            (12, "0:\n###\n\n99999999999999999999x1: 1\n"),
+        ];
+        for (n, input) in out_of_range {
+            let day = find(n).unwrap();
+            assert!(
+                day.solve(input, &day.all_parts()).is_err(),
+                "day {n}: {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn answers_print_as_given() {
+        assert_eq!(
+            Answer::from(367579641755680u128).to_string(),
+            "367579641755680"
+        );
+        assert_eq!(Answer::from(-3i64).as_str(), "-3");
+    }
+}
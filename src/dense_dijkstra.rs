@@ -0,0 +1,354 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, ControlFlow},
+};
+
+use crate::dijkstra::{
+    DijkstraConfig, DijkstraInput, DijkstraLookup, DijkstraObserver, NoObserver, PrevPathSet,
+};
+
+/// Implement this on top of [`DijkstraInput`] when the indexes of the
+/// graph can be numbered `0..num_indices()`. The search then works
+/// on flat vectors instead of hash maps.
+pub trait DenseDijkstraInput: DijkstraInput {
+    /// Number of distinct indexes in the graph.
+    fn num_indices(&self) -> usize;
+
+    /// Maps an index to its slot, must be below `num_indices()`.
+    fn index_to_usize(&self, x: &Self::Index) -> usize;
+
+    /// Inverse of `index_to_usize`.
+    fn usize_to_index(&self, n: usize) -> Self::Index;
+}
+
+const NOT_IN_HEAP: usize = usize::MAX;
+
+/// Binary min-heap over slots `0..n` which keeps track of where each
+/// slot lives so its cost can be decreased in place.
+struct IndexedHeap<C> {
+    heap: Vec<(C, usize)>,
+    positions: Vec<usize>,
+}
+
+impl<C: Ord + Copy> IndexedHeap<C> {
+    fn new(n: usize) -> Self {
+        Self {
+            heap: vec![],
+            positions: vec![NOT_IN_HEAP; n],
+        }
+    }
+
+    fn peek(&self) -> Option<(C, usize)> {
+        self.heap.first().copied()
+    }
+
+    /// Inserts `slot`, or lowers its cost if it is already queued.
+    fn push_or_decrease(&mut self, cost: C, slot: usize) {
+        let pos = self.positions[slot];
+        if pos == NOT_IN_HEAP {
+            self.heap.push((cost, slot));
+            self.positions[slot] = self.heap.len() - 1;
+            self.sift_up(self.heap.len() - 1);
+        } else if cost < self.heap[pos].0 {
+            self.heap[pos].0 = cost;
+            self.sift_up(pos);
+        }
+    }
+
+    fn pop(&mut self) -> Option<(C, usize)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let top = self.heap.pop().unwrap();
+        self.positions[top.1] = NOT_IN_HEAP;
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some(top)
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a].1] = a;
+        self.positions[self.heap[b].1] = b;
+    }
+
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if self.heap[pos] >= self.heap[parent] {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let left = 2 * pos + 1;
+            let right = left + 1;
+            let mut smallest = pos;
+            if left < self.heap.len() && self.heap[left] < self.heap[smallest] {
+                smallest = left;
+            }
+            if right < self.heap.len() && self.heap[right] < self.heap[smallest] {
+                smallest = right;
+            }
+            if smallest == pos {
+                break;
+            }
+            self.swap(pos, smallest);
+            pos = smallest;
+        }
+    }
+}
+
+/// Result of a [`DenseDijkstraMap`] search, one optional entry per
+/// slot of the index space.
+pub struct DenseResult<'a, T, U>
+where
+    T: DenseDijkstraInput,
+{
+    dijkstra_input: &'a T,
+    entries: Vec<Option<(T::Cost, U)>>,
+}
+
+impl<T, U> DenseResult<'_, T, U>
+where
+    T: DenseDijkstraInput,
+{
+    /// Number of settled indexes.
+    pub fn len(&self) -> usize {
+        self.entries.iter().filter(|e| e.is_some()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(|e| e.is_none())
+    }
+
+    pub fn contains_key(&self, x: &T::Index) -> bool {
+        self.lookup(x).is_some()
+    }
+
+    /// Iterates over all settled indexes in slot order.
+    pub fn iter(&self) -> impl Iterator<Item = (T::Index, &(T::Cost, U))> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(n, e)| Some((self.dijkstra_input.usize_to_index(n), e.as_ref()?)))
+    }
+}
+
+impl<T, U> DijkstraLookup<T, U> for DenseResult<'_, T, U>
+where
+    T: DenseDijkstraInput,
+{
+    fn lookup(&self, x: &T::Index) -> Option<&(T::Cost, U)> {
+        self.entries
+            .get(self.dijkstra_input.index_to_usize(x))?
+            .as_ref()
+    }
+}
+
+/// Same algorithm as [`crate::dijkstra::DijkstraMap`] for inputs
+/// with a bounded index space. Best costs live in flat vectors and
+/// the frontier is an indexed heap with decrease-key, so there is no
+/// hashing and no stale heap entries.
+pub struct DenseDijkstraMap<'a, T, U, O = NoObserver>
+where
+    T: DenseDijkstraInput,
+    U: PrevPathSet<T::Index>,
+{
+    dijkstra_input: &'a T,
+    unvisited_best_paths: Vec<Option<(T::Cost, U)>>,
+    unvisited: IndexedHeap<T::Cost>,
+    visited: DenseResult<'a, T, U>,
+    num_visited: usize,
+
+    config: DijkstraConfig<O>,
+}
+
+impl<'a, T, U, O> DenseDijkstraMap<'a, T, U, O>
+where
+    T: DenseDijkstraInput,
+    T::Cost: Ord + Eq + PartialEq + Debug + Add<Output = T::Cost> + Clone + Copy,
+    T::Index: Eq + PartialEq + Debug + Clone + Copy,
+    U: PrevPathSet<T::Index>,
+    O: DijkstraObserver<T::Index, T::Cost>,
+{
+    /// Pass in an instance of your type implementing DenseDijkstraInput
+    pub fn new(dijkstra_input: &'a T, config: DijkstraConfig<O>) -> Self {
+        let n = dijkstra_input.num_indices();
+        Self {
+            dijkstra_input,
+            unvisited_best_paths: (0..n).map(|_| None).collect(),
+            unvisited: IndexedHeap::new(n),
+            visited: DenseResult {
+                dijkstra_input,
+                entries: (0..n).map(|_| None).collect(),
+            },
+            num_visited: 0,
+            config,
+        }
+    }
+
+    /// Run the algorithm from a starting location. Returns the best
+    /// cost and previous index of every reachable index, see
+    /// [`crate::dijkstra::DijkstraMap::run`].
+    pub fn run(&mut self, start: (T::Cost, T::Index)) -> &DenseResult<'a, T, U> {
+        self.search(start, |_| false);
+        &self.visited
+    }
+
+    /// Run the algorithm until a node matching `is_goal` is settled,
+    /// see [`crate::dijkstra::DijkstraMap::run_until`].
+    pub fn run_until<G>(
+        &mut self,
+        start: (T::Cost, T::Index),
+        is_goal: G,
+    ) -> (Option<T::Index>, &DenseResult<'a, T, U>)
+    where
+        G: Fn(&T::Index) -> bool,
+    {
+        let goal = self.search(start, is_goal);
+        (goal, &self.visited)
+    }
+
+    pub fn observer(&self) -> &O {
+        &self.config.observer
+    }
+
+    fn settle(&mut self, slot: usize, cur_index: &T::Index) -> ControlFlow<()> {
+        self.visited.entries[slot] = self.unvisited_best_paths[slot].take();
+        self.num_visited += 1;
+        let cost = &self.visited.entries[slot].as_ref().unwrap().0;
+        self.config.observer.on_settle(cur_index, cost)
+    }
+
+    fn search<G>(&mut self, start: (T::Cost, T::Index), is_goal: G) -> Option<T::Index>
+    where
+        G: Fn(&T::Index) -> bool,
+    {
+        let input = self.dijkstra_input;
+        let start_slot = input.index_to_usize(&start.1);
+        self.unvisited_best_paths[start_slot] = Some((start.0, U::init(start.1)));
+        self.unvisited.push_or_decrease(start.0, start_slot);
+        self.config.observer.on_push(&start.1, &start.0);
+
+        let goal = self.search_loop(is_goal);
+        self.config.observer.on_finish(self.num_visited);
+        goal
+    }
+
+    fn search_loop<G>(&mut self, is_goal: G) -> Option<T::Index>
+    where
+        G: Fn(&T::Index) -> bool,
+    {
+        let input = self.dijkstra_input;
+        let mut goal: Option<(T::Cost, usize)> = None;
+
+        loop {
+            // hold back the goal until nothing tied with it is left,
+            // same as the hash map backend
+            if let Some((goal_cost, goal_slot)) = goal {
+                let tied = self.unvisited.peek().is_some_and(|(c, _)| c <= goal_cost);
+                if !tied {
+                    let goal_index = input.usize_to_index(goal_slot);
+                    let _ = self.settle(goal_slot, &goal_index);
+                    return Some(goal_index);
+                }
+            }
+
+            let (cost, slot) = self.unvisited.pop()?;
+            let cur_index = input.usize_to_index(slot);
+
+            if goal.is_none() && is_goal(&cur_index) {
+                goal = Some((cost, slot));
+                continue;
+            }
+
+            if self.settle(slot, &cur_index).is_break() {
+                return None;
+            }
+
+            input.for_each_adjacent(&cur_index, |neighbor_cost, neighbor_index| {
+                let neighbor_slot = input.index_to_usize(&neighbor_index);
+                if self.visited.entries[neighbor_slot].is_some() {
+                    return;
+                }
+
+                let alt_cost = neighbor_cost + cost;
+                self.config
+                    .observer
+                    .on_relax(&cur_index, &neighbor_index, &alt_cost);
+                match &mut self.unvisited_best_paths[neighbor_slot] {
+                    Some(val) if val.0 < alt_cost => (),
+                    Some(val) if val.0 == alt_cost => val.1.append(cur_index),
+                    entry => {
+                        *entry = Some((alt_cost, U::init(cur_index)));
+                        self.unvisited.push_or_decrease(alt_cost, neighbor_slot);
+                        self.config.observer.on_push(&neighbor_index, &alt_cost);
+                    }
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dijkstra::DijkstraMap, test_graphs::Grid};
+
+    /// Plenty of equal cost routes.
+    fn grid() -> Grid {
+        Grid::parse(&[
+            "11129", //
+            "15191", //
+            "11111", //
+            "71811", //
+            "11111", //
+        ])
+    }
+
+    #[test]
+    fn dense_matches_hash_map_backend() {
+        let grid = grid();
+
+        let mut dmap = DijkstraMap::<_, Vec<(i32, i32)>>::new(&grid, DijkstraConfig::default());
+        let expected = dmap.run((0, (0, 0))).clone();
+
+        let mut dense =
+            DenseDijkstraMap::<_, Vec<(i32, i32)>>::new(&grid, DijkstraConfig::default());
+        let result = dense.run((0, (0, 0)));
+
+        assert_eq!(result.len(), expected.len());
+        for (idx, (cost, prev)) in result.iter() {
+            let (expected_cost, expected_prev) = &expected[&idx];
+            let mut prev = prev.clone();
+            let mut expected_prev = expected_prev.clone();
+            prev.sort();
+            expected_prev.sort();
+            assert_eq!(cost, expected_cost);
+            assert_eq!(prev, expected_prev);
+        }
+
+        assert_eq!(
+            DijkstraMap::<Grid, Vec<(i32, i32)>>::count_all_paths::<u64>(&(0, 0), &(4, 4), result),
+            DijkstraMap::<Grid, Vec<(i32, i32)>>::count_all_paths(&(0, 0), &(4, 4), &expected),
+        );
+    }
+
+    #[test]
+    fn dense_run_until() {
+        let grid = grid();
+        let mut dense = DenseDijkstraMap::<_, (i32, i32)>::new(&grid, DijkstraConfig::default());
+        let (found, result) = dense.run_until((0, (0, 0)), |idx| *idx == (2, 2));
+        assert_eq!(found, Some((2, 2)));
+        assert_eq!(result.lookup(&(2, 2)).unwrap().0, 4);
+        assert!(!result.contains_key(&(4, 4)));
+    }
+}
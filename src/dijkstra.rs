@@ -1,15 +1,24 @@
 use std::{
     cmp::Reverse,
-    collections::{BinaryHeap, HashMap},
-    fmt::Debug,
+    collections::{BinaryHeap, HashMap, HashSet},
+    error::Error,
+    fmt::{self, Debug, Display},
     hash::Hash,
-    ops::Add,
+    mem,
+    ops::{Add, AddAssign, ControlFlow},
     time::Instant,
 };
 
+use num::{One, Zero};
+
+use crate::bidirectional::ReversibleInput;
+
 pub trait PrevPathSet<T> {
     fn init(val: T) -> Self;
     fn append(&mut self, val: T);
+    fn indexes<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
 }
 
 // for single best path
@@ -21,6 +30,13 @@ impl<T> PrevPathSet<T> for T {
     fn init(val: T) -> Self {
         val
     }
+
+    fn indexes<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        std::iter::once(self)
+    }
 }
 
 // for all best paths
@@ -32,10 +48,18 @@ impl<T> PrevPathSet<T> for Vec<T> {
     fn init(val: T) -> Self {
         vec![val]
     }
+
+    fn indexes<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.as_slice().iter()
+    }
 }
 
 /// A user type needs to implement this trait in order for the
-/// algorithm to operate.
+/// algorithm to operate. Types that would rather return a list of
+/// adjacent nodes can implement [`AdjacencyList`] instead.
 pub trait DijkstraInput {
     /// This represents the type of the `cost` attribute in the graph
     /// to move between nodes.
@@ -45,18 +69,200 @@ pub trait DijkstraInput {
     /// graph.
     type Index;
 
+    /// Calls `f` with the cost and index of every node adjacent to
+    /// `x`. The searches expand nodes through this, so no list needs
+    /// to be allocated for every expanded node.
+    fn for_each_adjacent<F>(&self, x: &Self::Index, f: F)
+    where
+        F: FnMut(Self::Cost, Self::Index);
+}
+
+/// Simpler way to implement [`DijkstraInput`], at the price of a list
+/// allocated for every expanded node.
+pub trait AdjacencyList {
+    /// See [`DijkstraInput::Cost`].
+    type Cost;
+
+    /// See [`DijkstraInput::Index`].
+    type Index;
+
     /// This takes an index representing a node and must return a list
     /// of adjacent nodes and the cost required to reach each of those
     /// nodes.
     fn get_adjacent(&self, x: &Self::Index) -> Vec<(Self::Cost, Self::Index)>;
 }
 
-#[derive(Default)]
-pub struct DijkstraConfig {
-    pub print_1000: bool,
+impl<T: AdjacencyList> DijkstraInput for T {
+    type Cost = T::Cost;
+    type Index = T::Index;
+
+    fn for_each_adjacent<F>(&self, x: &Self::Index, mut f: F)
+    where
+        F: FnMut(Self::Cost, Self::Index),
+    {
+        for (cost, index) in self.get_adjacent(x) {
+            f(cost, index);
+        }
+    }
+}
+
+/// Implement this on top of [`DijkstraInput`] to be able to run an A*
+/// search towards a goal.
+pub trait AStarInput: DijkstraInput {
+    /// Estimate of the cost remaining from `x` to the goal. It must
+    /// never overestimate the real cost and should be consistent
+    /// (never drop by more than the cost of a move), otherwise the
+    /// returned costs are not guaranteed to be the best ones.
+    fn heuristic(&self, x: &Self::Index) -> Self::Cost;
+}
+
+/// Hooks into the progress of a search, e.g. to collect statistics,
+/// report progress or cancel the run. Every method does nothing by
+/// default.
+pub trait DijkstraObserver<I, C> {
+    /// A node was queued with a new best cost.
+    fn on_push(&mut self, _x: &I, _cost: &C) {}
+
+    /// A node was settled with its final cost. Returning
+    /// `ControlFlow::Break` cancels the rest of the search.
+    fn on_settle(&mut self, _x: &I, _cost: &C) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// The move from `from` to `to` was considered, reaching `to` at
+    /// `cost`.
+    fn on_relax(&mut self, _from: &I, _to: &I, _cost: &C) {}
+
+    /// The search stopped after settling `settled` nodes in total.
+    fn on_finish(&mut self, _settled: usize) {}
+}
+
+/// Observer that does nothing, the default.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoObserver;
+
+impl<I, C> DijkstraObserver<I, C> for NoObserver {}
+
+/// Prints the number of settled nodes and the time taken to stderr
+/// every `every` settled nodes.
+pub struct StderrProgress {
+    every: usize,
+    pushed: usize,
+    settled: usize,
+    timer: Instant,
+}
+
+impl StderrProgress {
+    pub fn new(every: usize) -> Self {
+        Self {
+            every,
+            pushed: 0,
+            settled: 0,
+            timer: Instant::now(),
+        }
+    }
+}
+
+impl<I, C> DijkstraObserver<I, C> for StderrProgress {
+    fn on_push(&mut self, _x: &I, _cost: &C) {
+        self.pushed += 1;
+    }
+
+    fn on_settle(&mut self, _x: &I, _cost: &C) -> ControlFlow<()> {
+        self.settled += 1;
+        if self.settled.is_multiple_of(self.every) {
+            eprintln!(
+                "dijkstra, pushed: {}, settled: {}, elapsed: {}",
+                self.pushed,
+                self.settled,
+                self.timer.elapsed().as_secs_f32()
+            );
+            self.timer = Instant::now();
+        }
+        ControlFlow::Continue(())
+    }
 }
 
-pub struct DijkstraMap<'a, T, U>
+/// Counts what the search did.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ExpansionCounter {
+    pub pushed: usize,
+    pub settled: usize,
+    pub relaxed: usize,
+}
+
+impl<I, C> DijkstraObserver<I, C> for ExpansionCounter {
+    fn on_push(&mut self, _x: &I, _cost: &C) {
+        self.pushed += 1;
+    }
+
+    fn on_settle(&mut self, _x: &I, _cost: &C) -> ControlFlow<()> {
+        self.settled += 1;
+        ControlFlow::Continue(())
+    }
+
+    fn on_relax(&mut self, _from: &I, _to: &I, _cost: &C) {
+        self.relaxed += 1;
+    }
+}
+
+// for running two observers at once
+impl<I, C, A, B> DijkstraObserver<I, C> for (A, B)
+where
+    A: DijkstraObserver<I, C>,
+    B: DijkstraObserver<I, C>,
+{
+    fn on_push(&mut self, x: &I, cost: &C) {
+        self.0.on_push(x, cost);
+        self.1.on_push(x, cost);
+    }
+
+    fn on_settle(&mut self, x: &I, cost: &C) -> ControlFlow<()> {
+        let first = self.0.on_settle(x, cost);
+        let second = self.1.on_settle(x, cost);
+        if first.is_break() || second.is_break() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn on_relax(&mut self, from: &I, to: &I, cost: &C) {
+        self.0.on_relax(from, to, cost);
+        self.1.on_relax(from, to, cost);
+    }
+
+    fn on_finish(&mut self, settled: usize) {
+        self.0.on_finish(settled);
+        self.1.on_finish(settled);
+    }
+}
+
+pub struct DijkstraConfig<O = NoObserver> {
+    pub observer: O,
+}
+
+impl Default for DijkstraConfig {
+    fn default() -> Self {
+        Self {
+            observer: NoObserver,
+        }
+    }
+}
+
+/// When a search started with [`DijkstraMap::resume`] should pause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchLimit<C> {
+    /// Run until every reachable node is settled.
+    Unlimited,
+    /// Pause after settling this many nodes.
+    Expansions(usize),
+    /// Pause once every node left on the frontier costs more than
+    /// this.
+    CostAbove(C),
+}
+
+pub struct DijkstraMap<'a, T, U, O = NoObserver>
 where
     T: DijkstraInput,
     T::Cost: Ord + Eq + PartialEq,
@@ -68,25 +274,68 @@ where
     unvisited_best_paths: HashMap<T::Index, (T::Cost, U)>,
     unvisited: BinaryHeap<Reverse<(T::Cost, T::Index)>>,
     visited: HashMap<T::Index, (T::Cost, U)>,
+    sources: HashMap<T::Index, T::Index>,
+
+    config: DijkstraConfig<O>,
+}
+
+/// Mapping from every settled index to the cost of reaching it and
+/// its previous index (or indexes, see [`PrevPathSet`]).
+pub type DijkstraResult<T, U> =
+    HashMap<<T as DijkstraInput>::Index, (<T as DijkstraInput>::Cost, U)>;
 
-    config: DijkstraConfig,
+/// Read access to the result of a search, so the path helpers work
+/// with the result of any backend.
+pub trait DijkstraLookup<T: DijkstraInput, U> {
+    /// Returns the best cost and previous index(es) of `x`, or `None`
+    /// if `x` was not reached.
+    fn lookup(&self, x: &T::Index) -> Option<&(T::Cost, U)>;
 }
 
-impl<'a, T, U> DijkstraMap<'a, T, U>
+/// Errors returned when extracting paths from a search result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathError<I> {
+    /// The index could not be reached from the requested start.
+    Unreachable(I),
+}
+
+impl<I: Debug> Display for PathError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::Unreachable(x) => write!(f, "{x:?} is not reachable from the start"),
+        }
+    }
+}
+
+impl<I: Debug> Error for PathError<I> {}
+
+impl<T, U> DijkstraLookup<T, U> for DijkstraResult<T, U>
+where
+    T: DijkstraInput,
+    T::Index: Eq + Hash,
+{
+    fn lookup(&self, x: &T::Index) -> Option<&(T::Cost, U)> {
+        self.get(x)
+    }
+}
+
+impl<'a, T, U, O> DijkstraMap<'a, T, U, O>
 where
     T: DijkstraInput,
     T::Cost: Ord + Eq + PartialEq + Debug + Add<Output = T::Cost> + Clone + Copy,
     T::Index: Eq + PartialEq + PartialOrd + Debug + Hash + Clone + Copy,
     (T::Cost, T::Index): Ord,
     U: PrevPathSet<T::Index>,
+    O: DijkstraObserver<T::Index, T::Cost>,
 {
     /// Pass in an instance of your type implementing DijkstraInput
-    pub fn new(dijkstra_input: &'a T, config: DijkstraConfig) -> Self {
+    pub fn new(dijkstra_input: &'a T, config: DijkstraConfig<O>) -> Self {
         Self {
             dijkstra_input,
             unvisited_best_paths: HashMap::new(),
             unvisited: BinaryHeap::new(),
             visited: HashMap::new(),
+            sources: HashMap::new(),
             config,
         }
     }
@@ -94,133 +343,1155 @@ where
     /// Run the algorithm from a starting location. Returns a mapping
     /// from all reachable indexes (from the starting location) to a
     /// previous index and the cost required to reach that index.
-    pub fn run(&mut self, start: (T::Cost, T::Index)) -> &HashMap<T::Index, (T::Cost, U)> {
+    pub fn run(&mut self, start: (T::Cost, T::Index)) -> &DijkstraResult<T, U> {
+        self.search([start], |cost, _| cost, |_| false);
+        &self.visited
+    }
+
+    /// Run the algorithm from several starting locations at once, each
+    /// node ends up with the cost from the nearest of them. Use
+    /// `source_of` to find out which one that was.
+    pub fn run_multi<I>(&mut self, starts: I) -> &DijkstraResult<T, U>
+    where
+        I: IntoIterator<Item = (T::Cost, T::Index)>,
+    {
+        self.search(starts, |cost, _| cost, |_| false);
+        &self.visited
+    }
+
+    /// The starting location a settled node was reached from.
+    pub fn source_of(&self, x: &T::Index) -> Option<T::Index> {
+        if self.visited.contains_key(x) {
+            self.sources.get(x).copied()
+        } else {
+            None
+        }
+    }
+
+    pub fn observer(&self) -> &O {
+        &self.config.observer
+    }
+
+    /// Consumes the map, returning the mapping of all nodes settled by
+    /// the previous runs.
+    pub fn into_visited(self) -> DijkstraResult<T, U> {
+        self.visited
+    }
+
+    /// Run the algorithm from a starting location until a node
+    /// matching `is_goal` is settled. Returns that node (if one was
+    /// reachable) along with the mapping of all nodes settled so
+    /// far. When collecting all best paths, every node tied with the
+    /// goal is still expanded before stopping so that all of the
+    /// goal's equal cost previous indexes are recorded.
+    pub fn run_until<G>(
+        &mut self,
+        start: (T::Cost, T::Index),
+        is_goal: G,
+    ) -> (Option<T::Index>, &DijkstraResult<T, U>)
+    where
+        G: Fn(&T::Index) -> bool,
+    {
+        let goal = self.search([start], |cost, _| cost, is_goal);
+        (goal, &self.visited)
+    }
+
+    /// Queues starting locations without searching, the search is
+    /// then driven by `resume`.
+    pub fn seed<I>(&mut self, starts: I)
+    where
+        I: IntoIterator<Item = (T::Cost, T::Index)>,
+    {
+        self.push_starts(starts, |cost, _| cost);
+    }
+
+    /// Continues the search until `limit` is hit or every reachable
+    /// node is settled, returning the mapping of all nodes settled so
+    /// far. Nodes are expanded by cost, like `run`.
+    pub fn resume(&mut self, limit: SearchLimit<T::Cost>) -> &DijkstraResult<T, U> {
+        self.search_loop(|cost, _| cost, |_| false, limit, false);
+        self.config.observer.on_finish(self.visited.len());
+        &self.visited
+    }
+
+    /// Whether every node reachable from the starts has been settled.
+    pub fn is_finished(&self) -> bool {
+        self.unvisited_best_paths.is_empty()
+    }
+
+    /// Nodes reached but not settled yet, with their best cost so far.
+    pub fn frontier(&self) -> impl Iterator<Item = (&T::Index, &T::Cost)> {
         self.unvisited_best_paths
-            .insert(start.1, (start.0, U::init(start.1)));
-        self.unvisited.push(Reverse((start.0, start.1)));
-
-        let mut done = false;
-        let mut timer = Instant::now();
-
-        while !done {
-            if self.config.print_1000 && self.visited.len() % 10000 == 0 {
-                println!(
-                    "dijkstra, unvisited: {}, visited: {}, elapsed: {}",
-                    self.unvisited.len(),
-                    self.visited.len(),
-                    timer.elapsed().as_secs_f32()
+            .iter()
+            .map(|(idx, (cost, _))| (idx, cost))
+    }
+
+    /// Forgets every previous run so the map can be run again from
+    /// other starts, keeping the allocations.
+    pub fn clear(&mut self) {
+        self.unvisited_best_paths.clear();
+        self.unvisited.clear();
+        self.visited.clear();
+        self.sources.clear();
+    }
+
+    /// Shared search loop. `priority` computes the heap ordering key
+    /// from the best known cost of a node (plain dijkstra uses the
+    /// cost itself, A* adds the heuristic). The search stops once a
+    /// node matching `is_goal` is settled and returns it, or when the
+    /// observer cancels it.
+    fn search<I, P, G>(&mut self, starts: I, priority: P, is_goal: G) -> Option<T::Index>
+    where
+        I: IntoIterator<Item = (T::Cost, T::Index)>,
+        P: Fn(T::Cost, &T::Index) -> T::Cost,
+        G: Fn(&T::Index) -> bool,
+    {
+        self.push_starts(starts, &priority);
+        let goal = self.search_loop(priority, is_goal, SearchLimit::Unlimited, false);
+        self.config.observer.on_finish(self.visited.len());
+        goal
+    }
+
+    fn push_starts<I, P>(&mut self, starts: I, priority: P)
+    where
+        I: IntoIterator<Item = (T::Cost, T::Index)>,
+        P: Fn(T::Cost, &T::Index) -> T::Cost,
+    {
+        for (start_cost, start_index) in starts {
+            if self
+                .unvisited_best_paths
+                .get(&start_index)
+                .is_some_and(|(cost, _)| *cost <= start_cost)
+            {
+                continue;
+            }
+            self.unvisited_best_paths
+                .insert(start_index, (start_cost, U::init(start_index)));
+            self.unvisited
+                .push(Reverse((priority(start_cost, &start_index), start_index)));
+            self.sources.insert(start_index, start_index);
+            self.config.observer.on_push(&start_index, &start_cost);
+        }
+    }
+
+    /// Settles nodes until the goal, the limit or the end of the
+    /// frontier is reached. With `reopen` set, settled nodes that get
+    /// a cheaper path are expanded again (see `repair`).
+    fn search_loop<P, G>(
+        &mut self,
+        priority: P,
+        is_goal: G,
+        limit: SearchLimit<T::Cost>,
+        reopen: bool,
+    ) -> Option<T::Index>
+    where
+        P: Fn(T::Cost, &T::Index) -> T::Cost,
+        G: Fn(&T::Index) -> bool,
+    {
+        let mut goal: Option<(T::Cost, T::Index)> = None;
+        let mut expanded = 0;
+
+        loop {
+            // the goal is held back until nothing tied with it is left
+            // to expand, any of those may still be a previous index
+            if let Some((goal_priority, goal_index)) = goal {
+                let tied = self
+                    .unvisited
+                    .peek()
+                    .is_some_and(|Reverse((p, _))| *p <= goal_priority);
+                if !tied {
+                    let best = self.unvisited_best_paths.remove(&goal_index).unwrap();
+                    let _ = self.config.observer.on_settle(&goal_index, &best.0);
+                    self.visited.insert(goal_index, best);
+                    return Some(goal_index);
+                }
+            }
+
+            let paused = match limit {
+                SearchLimit::Unlimited => false,
+                SearchLimit::Expansions(n) => expanded >= n,
+                SearchLimit::CostAbove(bound) => self
+                    .unvisited
+                    .peek()
+                    .is_some_and(|Reverse((p, _))| *p > bound),
+            };
+            if paused {
+                return None;
+            }
+
+            let Reverse((cur_priority, cur_index)) = self.unvisited.pop()?;
+
+            // a node is pushed again every time its cost improves, the
+            // older entries are left behind and skipped here
+            if !self.unvisited_best_paths.contains_key(&cur_index)
+                || goal.is_some_and(|(_, goal_index)| goal_index == cur_index)
+            {
+                continue;
+            }
+
+            if goal.is_none() && is_goal(&cur_index) {
+                goal = Some((cur_priority, cur_index));
+                continue;
+            }
+
+            let (cost, prev_idxs) = self.unvisited_best_paths.remove(&cur_index).unwrap();
+            self.visited.insert(cur_index, (cost, prev_idxs));
+            expanded += 1;
+
+            if self.config.observer.on_settle(&cur_index, &cost).is_break() {
+                return None;
+            }
+
+            let input = self.dijkstra_input;
+            let source = self.sources[&cur_index];
+            input.for_each_adjacent(&cur_index, |neighbor_cost, neighbor_index| {
+                let alt_cost = neighbor_cost + cost;
+                self.relax(
+                    cur_index,
+                    neighbor_index,
+                    alt_cost,
+                    source,
+                    &priority,
+                    reopen,
                 );
-                timer = Instant::now();
-            }
-
-            let cur_index = self.unvisited.pop();
-
-            if let Some(cur_index) = cur_index {
-                let cur_index = cur_index.0;
-                let (cost, cur_index) = cur_index;
-                let (old_cost, prev_idxs) = self.unvisited_best_paths.remove(&cur_index).unwrap();
-                self.visited.insert(cur_index, (old_cost, prev_idxs));
-
-                let indexes = self.dijkstra_input.get_adjacent(&cur_index);
-                for (neighbor_cost, neighbor_index) in indexes
-                    .iter()
-                    .filter(|(_, index)| !self.visited.contains_key(index))
-                {
-                    let alt_cost = *neighbor_cost + cost;
-                    if let Some(val) = self.unvisited_best_paths.get_mut(neighbor_index) {
-                        if val.0 > alt_cost {
-                            val.0 = alt_cost;
-                            val.1 = U::init(cur_index);
-                        } else if val.0 == alt_cost {
-                            val.1.append(cur_index);
-                        }
-                    } else {
-                        self.unvisited.push(Reverse((alt_cost, *neighbor_index)));
-                        let new_item = (alt_cost, U::init(cur_index));
-                        self.unvisited_best_paths.insert(*neighbor_index, new_item);
-                    }
+            });
+        }
+    }
+
+    /// Offers `to` a path through `from` costing `alt_cost`, queueing
+    /// it again if that is an improvement.
+    #[inline]
+    fn relax<P>(
+        &mut self,
+        from: T::Index,
+        to: T::Index,
+        alt_cost: T::Cost,
+        source: T::Index,
+        priority: &P,
+        reopen: bool,
+    ) where
+        P: Fn(T::Cost, &T::Index) -> T::Cost,
+    {
+        if let Some(val) = self.visited.get_mut(&to) {
+            if !reopen || alt_cost > val.0 {
+                return;
+            }
+            if alt_cost == val.0 {
+                if !val.1.indexes().any(|prev| *prev == from) {
+                    val.1.append(from);
                 }
+                return;
             }
-            if self.unvisited.is_empty() {
-                done = true;
+            self.visited.remove(&to);
+        }
+
+        self.config.observer.on_relax(&from, &to, &alt_cost);
+        if let Some(val) = self.unvisited_best_paths.get_mut(&to) {
+            if val.0 > alt_cost {
+                val.0 = alt_cost;
+                val.1 = U::init(from);
+            } else {
+                // when repairing, the same move can be offered twice
+                if val.0 == alt_cost && !(reopen && val.1.indexes().any(|prev| *prev == from)) {
+                    val.1.append(from);
+                }
+                return;
             }
+        } else {
+            self.unvisited_best_paths
+                .insert(to, (alt_cost, U::init(from)));
         }
 
-        &self.visited
+        self.unvisited.push(Reverse((priority(alt_cost, &to), to)));
+        self.sources.insert(to, source);
+        self.config.observer.on_push(&to, &alt_cost);
     }
 
+    /// Follows the previous indexes back from `end` and returns the
+    /// best path from `start` to `end`, both included.
     pub fn extract_path(
         start: &T::Index,
         end: &T::Index,
-        data: &HashMap<T::Index, (T::Cost, T::Index)>,
-    ) -> Vec<T::Index> {
-        if start == end {
-            vec![]
-        } else {
-            let mut path = vec![end.clone()];
-            let pre_end = data.get(end).unwrap();
-            let new_path = Self::extract_path(start, &pre_end.1, data);
-            path.extend(new_path);
-            path
-        }
+        data: &impl DijkstraLookup<T, T::Index>,
+    ) -> Result<Vec<T::Index>, PathError<T::Index>> {
+        Self::walk_back(Some(start), end, data)
     }
 
-    fn extract_all_paths_aux(
-        start: &T::Index,
+    /// Same as `extract_path` but stops at whichever starting location
+    /// is reached first, for results of `run_multi`.
+    pub fn extract_path_to_source(
         end: &T::Index,
-        data: &HashMap<T::Index, (T::Cost, Vec<T::Index>)>,
-        prev: Vec<T::Index>,
-    ) -> Vec<Vec<T::Index>> {
-        if start == end {
-            vec![prev]
-        } else {
-            let pre_ends = data.get(end).unwrap();
-            let mut paths = vec![];
-            for pre_end in pre_ends.1.iter() {
-                let mut prev = prev.clone();
-                prev.push(pre_end.clone());
-                let new_paths = Self::extract_all_paths_aux(start, pre_end, data, prev);
-                paths.extend(new_paths);
+        data: &impl DijkstraLookup<T, T::Index>,
+    ) -> Result<Vec<T::Index>, PathError<T::Index>> {
+        Self::walk_back(None, end, data)
+    }
+
+    fn walk_back(
+        start: Option<&T::Index>,
+        end: &T::Index,
+        data: &impl DijkstraLookup<T, T::Index>,
+    ) -> Result<Vec<T::Index>, PathError<T::Index>> {
+        let mut path = vec![*end];
+        let mut cur = *end;
+        while start.is_none_or(|start| cur != *start) {
+            let (_, prev) = data.lookup(&cur).ok_or(PathError::Unreachable(*end))?;
+            // only a starting location is its own previous index
+            if *prev == cur {
+                if start.is_none() {
+                    break;
+                }
+                return Err(PathError::Unreachable(*end));
             }
-            paths
+            cur = *prev;
+            path.push(cur);
         }
+        path.reverse();
+        Ok(path)
     }
 
+    /// Same as `extract_path` but returns every tied best path from
+    /// `start` to `end`.
     pub fn extract_all_paths(
         start: &T::Index,
         end: &T::Index,
-        data: &HashMap<T::Index, (T::Cost, Vec<T::Index>)>,
-    ) -> Vec<Vec<T::Index>> {
-        Self::extract_all_paths_aux(start, end, data, vec![end.clone()])
+        data: &impl DijkstraLookup<T, Vec<T::Index>>,
+    ) -> Result<Vec<Vec<T::Index>>, PathError<T::Index>> {
+        Self::walk_back_all(Some(start), end, data)
     }
 
-    pub fn count_all_paths_aux(
+    /// Same as `extract_all_paths` but every path stops at whichever
+    /// starting location it reaches, for results of `run_multi`.
+    pub fn extract_all_paths_to_source(
+        end: &T::Index,
+        data: &impl DijkstraLookup<T, Vec<T::Index>>,
+    ) -> Result<Vec<Vec<T::Index>>, PathError<T::Index>> {
+        Self::walk_back_all(None, end, data)
+    }
+
+    fn walk_back_all(
+        start: Option<&T::Index>,
+        end: &T::Index,
+        data: &impl DijkstraLookup<T, Vec<T::Index>>,
+    ) -> Result<Vec<Vec<T::Index>>, PathError<T::Index>> {
+        let mut paths = vec![];
+        // partial paths, stored backwards from `end`
+        let mut stack = vec![vec![*end]];
+        while let Some(mut path) = stack.pop() {
+            let cur = *path.last().unwrap();
+            if start.is_some_and(|start| cur == *start) {
+                path.reverse();
+                paths.push(path);
+                continue;
+            }
+            let (_, prevs) = data.lookup(&cur).ok_or(PathError::Unreachable(*end))?;
+            if start.is_none() && prevs.contains(&cur) {
+                let mut path = path.clone();
+                path.reverse();
+                paths.push(path);
+            }
+            let mut prevs = prevs.iter().rev().filter(|prev| **prev != cur).peekable();
+            while let Some(prev) = prevs.next() {
+                // the last branch can take over the partial path
+                let mut path = if prevs.peek().is_some() {
+                    path.clone()
+                } else {
+                    mem::take(&mut path)
+                };
+                path.push(*prev);
+                stack.push(path);
+            }
+        }
+
+        if paths.is_empty() {
+            Err(PathError::Unreachable(*end))
+        } else {
+            Ok(paths)
+        }
+    }
+
+    /// Number of tied best paths from `start` to `end` (`0` if `end`
+    /// was not reached from `start`). `memoized_count` holds the
+    /// totals of the `(start, x)` pairs counted so far and can be
+    /// shared between calls on the same result. Recurses once per node
+    /// on the path, see `count_all_paths_iterative` for long paths.
+    pub fn count_all_paths_aux<N>(
         start: &T::Index,
         end: &T::Index,
-        data: &HashMap<T::Index, (T::Cost, Vec<T::Index>)>,
-        memoized_count: &mut HashMap<(T::Index, T::Index), u64>,
-    ) -> u64 {
-        let mut count = 0;
+        data: &impl DijkstraLookup<T, Vec<T::Index>>,
+        memoized_count: &mut HashMap<(T::Index, T::Index), N>,
+    ) -> N
+    where
+        N: Clone + Zero + One + for<'n> AddAssign<&'n N>,
+    {
         if start == end {
-            count = 1;
-        } else if let Some(val) = memoized_count.get(&(*start, *end)) {
-            count += val;
-        } else {
-            let pre_ends = data.get(end).unwrap();
-            for pre_end in pre_ends.1.iter() {
-                count += Self::count_all_paths_aux(start, pre_end, data, memoized_count);
-                memoized_count.insert((*start, *end), count);
+            return N::one();
+        }
+        if let Some(count) = memoized_count.get(&(*start, *end)) {
+            return count.clone();
+        }
+
+        let mut count = N::zero();
+        if let Some((_, pre_ends)) = data.lookup(end) {
+            // other starting locations are their own previous index
+            for pre_end in pre_ends.iter().filter(|pre_end| *pre_end != end) {
+                count += &Self::count_all_paths_aux(start, pre_end, data, memoized_count);
             }
         }
+        memoized_count.insert((*start, *end), count.clone());
         count
     }
 
-    pub fn count_all_paths(
+    /// Number of tied best paths from `start` to `end`, counted in
+    /// `N` (e.g. `u64`, `u128` or `num::BigUint` when that overflows).
+    pub fn count_all_paths<N>(
         start: &T::Index,
         end: &T::Index,
-        data: &HashMap<T::Index, (T::Cost, Vec<T::Index>)>,
-    ) -> u64 {
+        data: &impl DijkstraLookup<T, Vec<T::Index>>,
+    ) -> N
+    where
+        N: Clone + Zero + One + for<'n> AddAssign<&'n N>,
+    {
         let mut memo = HashMap::new();
         Self::count_all_paths_aux(start, end, data, &mut memo)
     }
+
+    /// Same as `count_all_paths` but with an explicit stack, so any
+    /// path length works.
+    pub fn count_all_paths_iterative<N>(
+        start: &T::Index,
+        end: &T::Index,
+        data: &impl DijkstraLookup<T, Vec<T::Index>>,
+    ) -> N
+    where
+        N: Clone + Zero + One + for<'n> AddAssign<&'n N>,
+    {
+        let pre_ends = |x: T::Index| {
+            let pre_ends = data.lookup(&x).map_or(&[][..], |(_, pre_ends)| pre_ends);
+            // other starting locations are their own previous index
+            pre_ends.iter().filter(move |pre_end| **pre_end != x)
+        };
+
+        let mut counts: HashMap<T::Index, N> = HashMap::new();
+        // a node is visited a second time once all of its previous
+        // indexes are counted
+        let mut stack = vec![(*end, false)];
+        while let Some((x, ready)) = stack.pop() {
+            if counts.contains_key(&x) {
+                continue;
+            }
+            if x == *start {
+                counts.insert(x, N::one());
+            } else if ready {
+                let mut count = N::zero();
+                for pre_end in pre_ends(x) {
+                    count += &counts[pre_end];
+                }
+                counts.insert(x, count);
+            } else {
+                stack.push((x, true));
+                stack.extend(
+                    pre_ends(x)
+                        .filter(|pre_end| !counts.contains_key(*pre_end))
+                        .map(|pre_end| (*pre_end, false)),
+                );
+            }
+        }
+        counts.remove(end).unwrap_or_else(N::zero)
+    }
+}
+
+impl<T, U, O> DijkstraMap<'_, T, U, O>
+where
+    T: AStarInput,
+    T::Cost: Ord + Eq + PartialEq + Debug + Add<Output = T::Cost> + Clone + Copy,
+    T::Index: Eq + PartialEq + PartialOrd + Debug + Hash + Clone + Copy,
+    (T::Cost, T::Index): Ord,
+    U: PrevPathSet<T::Index>,
+    O: DijkstraObserver<T::Index, T::Cost>,
+{
+    /// Run an A* search from a starting location, guided by
+    /// [`AStarInput::heuristic`]. Stops as soon as a node matching
+    /// `is_goal` is settled and returns that node (if one was
+    /// reachable) along with the mapping of all nodes settled so far.
+    pub fn run_astar<G>(
+        &mut self,
+        start: (T::Cost, T::Index),
+        is_goal: G,
+    ) -> (Option<T::Index>, &DijkstraResult<T, U>)
+    where
+        G: Fn(&T::Index) -> bool,
+    {
+        let input = self.dijkstra_input;
+        let goal = self.search([start], |cost, idx| cost + input.heuristic(idx), is_goal);
+        (goal, &self.visited)
+    }
+}
+
+impl<'a, T, U, O> DijkstraMap<'a, T, U, O>
+where
+    T: ReversibleInput,
+    T::Cost: Ord + Eq + PartialEq + Debug + Add<Output = T::Cost> + Clone + Copy,
+    T::Index: Eq + PartialEq + PartialOrd + Debug + Hash + Clone + Copy,
+    (T::Cost, T::Index): Ord,
+    U: PrevPathSet<T::Index>,
+    O: DijkstraObserver<T::Index, T::Cost>,
+{
+    /// Brings the result of the previous runs up to date after the
+    /// moves out of the `changed` nodes were re-costed, added or
+    /// removed, `dijkstra_input` being the changed graph. Only the
+    /// nodes whose best path went through a changed move, and the ones
+    /// that get cheaper, are searched again (in the spirit of LPA*).
+    /// Finishes the search, returning the mapping of all settled nodes.
+    pub fn repair<I>(&mut self, dijkstra_input: &'a T, changed: I) -> &DijkstraResult<T, U>
+    where
+        I: IntoIterator<Item = T::Index>,
+    {
+        self.dijkstra_input = dijkstra_input;
+        let changed = changed.into_iter().collect::<HashSet<_>>();
+
+        // starting locations keep their cost whatever happens
+        let mut children: HashMap<T::Index, Vec<T::Index>> = HashMap::new();
+        for (idx, (_, prev_idxs)) in self.visited.iter().chain(&self.unvisited_best_paths) {
+            if prev_idxs.indexes().any(|prev| prev == idx) {
+                continue;
+            }
+            for prev in prev_idxs.indexes() {
+                children.entry(*prev).or_default().push(*idx);
+            }
+        }
+
+        // everything reached through a changed move may now cost more
+        let mut invalid = HashSet::new();
+        let mut stack = changed
+            .iter()
+            .filter_map(|x| children.get(x))
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        while let Some(x) = stack.pop() {
+            if invalid.insert(x) {
+                stack.extend(children.get(&x).into_iter().flatten().copied());
+            }
+        }
+        for x in &invalid {
+            self.visited.remove(x);
+            self.unvisited_best_paths.remove(x);
+        }
+
+        // invalid nodes start over from whatever settled node leads to
+        // them, changed nodes that are still settled may make others
+        // cheaper
+        let priority = |cost, _: &T::Index| cost;
+        for x in &invalid {
+            dijkstra_input.for_each_reverse_adjacent(x, |move_cost, from| {
+                if let Some((cost, _)) = self.visited.get(&from) {
+                    let source = self.sources[&from];
+                    self.relax(from, *x, *cost + move_cost, source, &priority, true);
+                }
+            });
+        }
+        for from in changed {
+            let Some((cost, _)) = self.visited.get(&from) else {
+                continue;
+            };
+            let (cost, source) = (*cost, self.sources[&from]);
+            dijkstra_input.for_each_adjacent(&from, |move_cost, to| {
+                self.relax(from, to, cost + move_cost, source, &priority, true);
+            });
+        }
+
+        // heap entries of the invalid nodes may be cheaper than their
+        // new cost, which the search loop can not tell from a live one
+        self.unvisited = self
+            .unvisited_best_paths
+            .iter()
+            .map(|(idx, (cost, _))| Reverse((*cost, *idx)))
+            .collect();
+
+        self.search_loop(priority, |_| false, SearchLimit::Unlimited, true);
+        self.config.observer.on_finish(self.visited.len());
+        &self.visited
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_graphs::{Edges, Grid, adjacent, assert_same};
+
+    fn grid() -> Grid {
+        Grid::parse(&[
+            "13129", //
+            "15191", //
+            "42131", //
+            "71811", //
+            "11191", //
+        ])
+    }
+
+    /// Same graph as [`Edges`] implementing `for_each_adjacent`
+    /// directly rather than through [`AdjacencyList`].
+    struct VisitEdges(Edges);
+
+    impl DijkstraInput for VisitEdges {
+        type Cost = i32;
+        type Index = i32;
+
+        fn for_each_adjacent<F>(&self, x: &Self::Index, mut f: F)
+        where
+            F: FnMut(Self::Cost, Self::Index),
+        {
+            for (from, cost, to) in self.0.0.iter() {
+                if from == x {
+                    f(*cost, *to);
+                }
+            }
+        }
+    }
+
+    /// `0 -> 1 -> ... -> len - 1`, each step costing 1.
+    struct Chain(i32);
+
+    impl AdjacencyList for Chain {
+        type Cost = i32;
+        type Index = i32;
+
+        fn get_adjacent(&self, x: &Self::Index) -> Vec<(Self::Cost, Self::Index)> {
+            if *x + 1 < self.0 {
+                vec![(1, *x + 1)]
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    /// `(0, 0)` to `(n, n)`, moving right or down for 1. There are
+    /// `binomial(2n, n)` best paths from corner to corner.
+    struct Lattice(i32);
+
+    impl AdjacencyList for Lattice {
+        type Cost = i32;
+        type Index = (i32, i32);
+
+        fn get_adjacent(&self, &(r, c): &Self::Index) -> Vec<(Self::Cost, Self::Index)> {
+            [(r + 1, c), (r, c + 1)]
+                .into_iter()
+                .filter(|(r, c)| *r <= self.0 && *c <= self.0)
+                .map(|idx| (1, idx))
+                .collect()
+        }
+    }
+
+    fn binomial(n: u32, k: u32) -> num::BigUint {
+        (0..k).fold(num::BigUint::one(), |acc, i| acc * (n - i) / (i + 1))
+    }
+
+    impl AStarInput for Grid {
+        fn heuristic(&self, &(r, c): &Self::Index) -> Self::Cost {
+            // every cell costs at least 1
+            let (gr, gc) = self.goal();
+            (gr - r).abs() + (gc - c).abs()
+        }
+    }
+
+    /// Same grid without a heuristic, which makes A* plain Dijkstra.
+    struct Unguided(Grid);
+
+    impl DijkstraInput for Unguided {
+        type Cost = i32;
+        type Index = (i32, i32);
+
+        fn for_each_adjacent<F>(&self, x: &Self::Index, f: F)
+        where
+            F: FnMut(Self::Cost, Self::Index),
+        {
+            self.0.for_each_adjacent(x, f);
+        }
+    }
+
+    impl AStarInput for Unguided {
+        fn heuristic(&self, _: &Self::Index) -> Self::Cost {
+            0
+        }
+    }
+
+    #[test]
+    fn astar_zero_heuristic_matches_run() {
+        let grid = Unguided(grid());
+
+        let mut dmap = DijkstraMap::<_, (i32, i32)>::new(&grid, DijkstraConfig::default());
+        let full = dmap.run((0, (0, 0))).clone();
+
+        for (goal, (cost, _)) in full.iter() {
+            let mut dmap = DijkstraMap::<_, (i32, i32)>::new(&grid, DijkstraConfig::default());
+            let (found, visited) = dmap.run_astar((0, (0, 0)), |idx| idx == goal);
+            assert_eq!(found, Some(*goal));
+            assert_eq!(visited[goal].0, *cost);
+            for (idx, (cost, _)) in visited.iter() {
+                assert_eq!(full[idx].0, *cost);
+            }
+        }
+    }
+
+    #[test]
+    fn astar_heuristic_finds_best_cost_with_fewer_expansions() {
+        let guided = grid();
+        let goal = guided.goal();
+
+        let unguided = Unguided(grid());
+        let mut dmap = DijkstraMap::<_, (i32, i32)>::new(&unguided, DijkstraConfig::default());
+        let (_, zero_visited) = dmap.run_astar((0, (0, 0)), |idx| *idx == goal);
+        let zero_visited = zero_visited.clone();
+
+        let mut dmap = DijkstraMap::<_, (i32, i32)>::new(&guided, DijkstraConfig::default());
+        let (found, visited) = dmap.run_astar((0, (0, 0)), |idx| *idx == goal);
+
+        assert_eq!(found, Some(goal));
+        assert_eq!(visited[&goal].0, zero_visited[&goal].0);
+        assert!(visited.len() <= zero_visited.len());
+    }
+
+    #[test]
+    fn astar_unreachable_goal() {
+        let grid = grid();
+        let mut dmap = DijkstraMap::<_, (i32, i32)>::new(&grid, DijkstraConfig::default());
+        let (found, visited) = dmap.run_astar((0, (0, 0)), |idx| *idx == (10, 10));
+        assert_eq!(found, None);
+        assert_eq!(visited.len(), 25);
+    }
+
+    #[test]
+    fn run_until_stops_early() {
+        let grid = grid();
+        let goal = grid.goal();
+
+        let mut dmap = DijkstraMap::<_, (i32, i32)>::new(&grid, DijkstraConfig::default());
+        let full = dmap.run((0, (0, 0))).clone();
+
+        let mut dmap = DijkstraMap::<_, (i32, i32)>::new(&grid, DijkstraConfig::default());
+        let (found, visited) = dmap.run_until((0, (0, 0)), |idx| *idx == (0, 1));
+        assert_eq!(found, Some((0, 1)));
+        assert_eq!(visited[&(0, 1)].0, full[&(0, 1)].0);
+        assert!(visited.len() < full.len());
+        assert!(!visited.contains_key(&goal));
+    }
+
+    #[test]
+    fn run_until_collects_tied_previous_indexes() {
+        // 0 -> 1 -> 3 and 0 -> 2 -> 3 cost the same, 0 -> 4 -> 3 is worse
+        let edges = Edges(vec![
+            (0, 1, 1),
+            (0, 2, 2),
+            (1, 2, 3),
+            (2, 1, 3),
+            (0, 1, 4),
+            (4, 5, 3),
+            (3, 1, 5),
+        ]);
+
+        let mut dmap = DijkstraMap::<_, Vec<i32>>::new(&edges, DijkstraConfig::default());
+        let full = dmap.run((0, 0)).clone();
+
+        let mut dmap = DijkstraMap::<_, Vec<i32>>::new(&edges, DijkstraConfig::default());
+        let (found, visited) = dmap.run_until((0, 0), |idx| *idx == 3);
+        assert_eq!(found, Some(3));
+        assert!(!visited.contains_key(&5));
+
+        let (cost, mut prev) = visited[&3].clone();
+        prev.sort();
+        assert_eq!(cost, 3);
+        assert_eq!(prev, vec![1, 2]);
+        assert_eq!(
+            DijkstraMap::<Edges, Vec<i32>>::count_all_paths::<u64>(&0, &3, visited),
+            DijkstraMap::<Edges, Vec<i32>>::count_all_paths(&0, &3, &full),
+        );
+    }
+
+    #[test]
+    fn run_until_expands_nodes_tied_with_goal() {
+        // 6 is only settled after the goal but reaches it for free
+        let edges = Edges(vec![(0, 3, 3), (0, 3, 6), (6, 0, 3)]);
+        let mut dmap = DijkstraMap::<_, Vec<i32>>::new(&edges, DijkstraConfig::default());
+        let (found, visited) = dmap.run_until((0, 0), |idx| *idx == 3);
+        assert_eq!(found, Some(3));
+        assert_eq!(visited[&3], (3, vec![0, 6]));
+    }
+
+    #[test]
+    fn run_until_goal_is_start() {
+        let edges = Edges(vec![(0, 1, 1)]);
+        let mut dmap = DijkstraMap::<_, i32>::new(&edges, DijkstraConfig::default());
+        let (found, visited) = dmap.run_until((0, 0), |idx| *idx == 0);
+        assert_eq!(found, Some(0));
+        assert_eq!(visited.len(), 1);
+    }
+
+    #[test]
+    fn adjacency_list_matches_for_each_adjacent() {
+        let edges = Edges(vec![(0, 1, 1), (0, 4, 2), (1, 1, 2), (2, 2, 3), (1, 5, 3)]);
+        let mut dmap = DijkstraMap::<_, Vec<i32>>::new(&edges, DijkstraConfig::default());
+        let expected = dmap.run((0, 0)).clone();
+
+        let visit_edges = VisitEdges(edges);
+        assert_eq!(adjacent(&visit_edges, &1), visit_edges.0.get_adjacent(&1));
+        let mut dmap = DijkstraMap::<_, Vec<i32>>::new(&visit_edges, DijkstraConfig::default());
+        assert_eq!(dmap.run((0, 0)), &expected);
+    }
+
+    #[test]
+    fn extract_path_trivial() {
+        let edges = Edges(vec![(0, 1, 1)]);
+        let mut dmap = DijkstraMap::<_, i32>::new(&edges, DijkstraConfig::default());
+        let result = dmap.run((0, 0));
+        assert_eq!(
+            DijkstraMap::<Edges, i32>::extract_path(&0, &0, result),
+            Ok(vec![0])
+        );
+        assert_eq!(
+            DijkstraMap::<Edges, i32>::extract_path(&0, &1, result),
+            Ok(vec![0, 1])
+        );
+    }
+
+    #[test]
+    fn extract_path_unreachable() {
+        let edges = Edges(vec![(0, 1, 1), (2, 1, 0)]);
+        let mut dmap = DijkstraMap::<_, i32>::new(&edges, DijkstraConfig::default());
+        let result = dmap.run((0, 0));
+        assert_eq!(
+            DijkstraMap::<Edges, i32>::extract_path(&0, &2, result),
+            Err(PathError::Unreachable(2))
+        );
+        // 1 was reached, but not from 1
+        assert_eq!(
+            DijkstraMap::<Edges, i32>::extract_path(&1, &0, result),
+            Err(PathError::Unreachable(0))
+        );
+
+        let mut dmap = DijkstraMap::<_, Vec<i32>>::new(&edges, DijkstraConfig::default());
+        let result = dmap.run((0, 0));
+        assert_eq!(
+            DijkstraMap::<Edges, Vec<i32>>::extract_all_paths(&0, &2, result),
+            Err(PathError::Unreachable(2))
+        );
+        assert_eq!(
+            DijkstraMap::<Edges, Vec<i32>>::extract_all_paths(&1, &0, result),
+            Err(PathError::Unreachable(0))
+        );
+    }
+
+    #[test]
+    fn extract_path_long_chain() {
+        let chain = Chain(200_000);
+        let mut dmap = DijkstraMap::<_, i32>::new(&chain, DijkstraConfig::default());
+        let result = dmap.run((0, 0));
+        let path = DijkstraMap::<Chain, i32>::extract_path(&0, &199_999, result).unwrap();
+        assert_eq!(path, (0..200_000).collect::<Vec<_>>());
+
+        let mut dmap = DijkstraMap::<_, Vec<i32>>::new(&chain, DijkstraConfig::default());
+        let result = dmap.run((0, 0));
+        let paths =
+            DijkstraMap::<Chain, Vec<i32>>::extract_all_paths(&0, &199_999, result).unwrap();
+        assert_eq!(paths, vec![(0..200_000).collect::<Vec<_>>()]);
+    }
+
+    #[test]
+    fn extract_all_paths_in_order() {
+        let edges = Edges(vec![(0, 1, 1), (0, 1, 2), (1, 1, 3), (2, 1, 3), (3, 1, 4)]);
+        let mut dmap = DijkstraMap::<_, Vec<i32>>::new(&edges, DijkstraConfig::default());
+        let result = dmap.run((0, 0));
+        let mut paths = DijkstraMap::<Edges, Vec<i32>>::extract_all_paths(&0, &4, result).unwrap();
+        paths.sort();
+        assert_eq!(paths, vec![vec![0, 1, 3, 4], vec![0, 2, 3, 4]]);
+    }
+
+    #[test]
+    fn run_multi_nearest_source() {
+        // two sources on a line 0 - 1 - 2 - 3 - 4 - 5 - 6, 6 starts later
+        let mut edges = vec![];
+        for n in 0..6 {
+            edges.push((n, 1, n + 1));
+            edges.push((n + 1, 1, n));
+        }
+        let edges = Edges(edges);
+
+        let mut dmap = DijkstraMap::<_, i32>::new(&edges, DijkstraConfig::default());
+        let result = dmap.run_multi([(0, 0), (2, 6)]).clone();
+        let costs = (0..7).map(|n| result[&n].0).collect::<Vec<_>>();
+        assert_eq!(costs, vec![0, 1, 2, 3, 4, 3, 2]);
+
+        let sources = (0..7).map(|n| dmap.source_of(&n)).collect::<Vec<_>>();
+        let expected = [0, 0, 0, 0, 0, 6, 6].map(Some);
+        assert_eq!(sources, expected);
+        assert_eq!(dmap.source_of(&7), None);
+
+        assert_eq!(
+            DijkstraMap::<Edges, i32>::extract_path_to_source(&5, &result),
+            Ok(vec![6, 5])
+        );
+        assert_eq!(
+            DijkstraMap::<Edges, i32>::extract_path_to_source(&0, &result),
+            Ok(vec![0])
+        );
+        assert_eq!(
+            DijkstraMap::<Edges, i32>::extract_path_to_source(&7, &result),
+            Err(PathError::Unreachable(7))
+        );
+    }
+
+    #[test]
+    fn run_multi_all_paths_to_source() {
+        // 1 and 2 are both sources, 3 is equally far from each
+        let edges = Edges(vec![(1, 1, 3), (2, 1, 3), (3, 1, 4)]);
+        let mut dmap = DijkstraMap::<_, Vec<i32>>::new(&edges, DijkstraConfig::default());
+        let result = dmap.run_multi([(0, 1), (0, 2)]);
+        let mut paths =
+            DijkstraMap::<Edges, Vec<i32>>::extract_all_paths_to_source(&4, result).unwrap();
+        paths.sort();
+        assert_eq!(paths, vec![vec![1, 3, 4], vec![2, 3, 4]]);
+    }
+
+    /// Cancels the search after settling a number of nodes.
+    struct StopAfter {
+        remaining: usize,
+        finished: Option<usize>,
+    }
+
+    impl<I, C> DijkstraObserver<I, C> for StopAfter {
+        fn on_settle(&mut self, _x: &I, _cost: &C) -> ControlFlow<()> {
+            self.remaining -= 1;
+            if self.remaining == 0 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+
+        fn on_finish(&mut self, settled: usize) {
+            self.finished = Some(settled);
+        }
+    }
+
+    #[test]
+    fn observer_counts_expansions() {
+        let grid = grid();
+        let config = DijkstraConfig {
+            observer: ExpansionCounter::default(),
+        };
+        let mut dmap = DijkstraMap::<_, (i32, i32), _>::new(&grid, config);
+        let settled = dmap.run((0, (0, 0))).len();
+
+        let counter = dmap.observer();
+        assert_eq!(counter.settled, settled);
+        assert!(counter.pushed >= settled);
+        // every cell has 2 to 4 neighbours and all are relaxed once
+        // from whichever side is settled first
+        assert_eq!(counter.relaxed, 40);
+    }
+
+    #[test]
+    fn observer_cancels_run() {
+        let grid = grid();
+        let config = DijkstraConfig {
+            observer: (
+                StopAfter {
+                    remaining: 5,
+                    finished: None,
+                },
+                ExpansionCounter::default(),
+            ),
+        };
+        let mut dmap = DijkstraMap::<_, (i32, i32), _>::new(&grid, config);
+        assert_eq!(dmap.run((0, (0, 0))).len(), 5);
+        assert_eq!(dmap.observer().0.finished, Some(5));
+        assert_eq!(dmap.observer().1.settled, 5);
+    }
+
+    #[test]
+    fn resume_in_steps_matches_run() {
+        let grid = grid();
+        let mut dmap = DijkstraMap::<_, Vec<(i32, i32)>>::new(&grid, DijkstraConfig::default());
+        let expected = dmap.run((0, (0, 0))).clone();
+
+        let mut dmap = DijkstraMap::<_, Vec<(i32, i32)>>::new(&grid, DijkstraConfig::default());
+        dmap.seed([(0, (0, 0))]);
+        let mut steps = 0;
+        while !dmap.is_finished() {
+            let settled = dmap.resume(SearchLimit::Expansions(3)).len();
+            steps += 1;
+            assert_eq!(settled, (steps * 3).min(25));
+        }
+        assert_eq!(steps, 9);
+        assert_same(&dmap.into_visited(), &expected);
+    }
+
+    #[test]
+    fn resume_up_to_cost_bound() {
+        let grid = grid();
+        let mut dmap = DijkstraMap::<_, Vec<(i32, i32)>>::new(&grid, DijkstraConfig::default());
+        let expected = dmap.run((0, (0, 0))).clone();
+
+        let mut dmap = DijkstraMap::<_, Vec<(i32, i32)>>::new(&grid, DijkstraConfig::default());
+        dmap.seed([(0, (0, 0))]);
+        let settled = dmap.resume(SearchLimit::CostAbove(5)).clone();
+        assert!(!dmap.is_finished());
+        assert!(settled.values().all(|(cost, _)| *cost <= 5));
+        assert!(dmap.frontier().all(|(_, cost)| *cost > 5));
+        let below = expected.values().filter(|(cost, _)| *cost <= 5).count();
+        assert_eq!(settled.len(), below);
+
+        dmap.resume(SearchLimit::Unlimited);
+        assert!(dmap.is_finished());
+        assert_eq!(dmap.frontier().count(), 0);
+        assert_same(&dmap.into_visited(), &expected);
+    }
+
+    #[test]
+    fn clear_allows_another_start() {
+        let grid = grid();
+        let mut dmap = DijkstraMap::<_, (i32, i32)>::new(&grid, DijkstraConfig::default());
+        dmap.run((0, (0, 0)));
+        dmap.clear();
+        let result = dmap.run((0, (4, 4))).clone();
+
+        let mut fresh = DijkstraMap::<_, (i32, i32)>::new(&grid, DijkstraConfig::default());
+        assert_eq!(&result, fresh.run((0, (4, 4))));
+    }
+
+    #[test]
+    fn repair_matches_fresh_run_after_cell_change() {
+        let grid = grid();
+        for r in 0..5 {
+            for c in 0..5 {
+                for value in [1, 20] {
+                    let mut changed_grid = grid.clone();
+                    changed_grid.set_cost((r, c), Some(value));
+                    // moving onto the cell costs its value, so only the
+                    // moves out of its neighbours changed
+                    let changed = adjacent(&changed_grid, &(r, c));
+
+                    let mut dmap = DijkstraMap::<_, Vec<(i32, i32)>>::new(
+                        &changed_grid,
+                        DijkstraConfig::default(),
+                    );
+                    let expected = dmap.run((0, (0, 0))).clone();
+
+                    let mut dmap =
+                        DijkstraMap::<_, Vec<(i32, i32)>>::new(&grid, DijkstraConfig::default());
+                    dmap.run((0, (0, 0)));
+                    let result = dmap.repair(&changed_grid, changed.into_iter().map(|(_, x)| x));
+                    assert_same(result, &expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn repair_after_edges_are_removed_and_added() {
+        let edges = Edges(vec![(0, 1, 1), (1, 1, 2), (0, 5, 2), (2, 1, 3)]);
+        let mut dmap = DijkstraMap::<_, i32, _>::new(
+            &edges,
+            DijkstraConfig {
+                observer: ExpansionCounter::default(),
+            },
+        );
+        dmap.run((0, 0));
+
+        // cut 1 -> 2, 2 and 3 fall back on 0 -> 2
+        let cut = Edges(vec![(0, 1, 1), (0, 5, 2), (2, 1, 3)]);
+        let result = dmap.repair(&cut, [1]);
+        assert_eq!(result[&2], (5, 0));
+        assert_eq!(result[&3], (6, 2));
+        // only 2 and 3 were settled again
+        assert_eq!(dmap.observer().settled, 4 + 2);
+
+        // a shortcut from 1 to 3 and a new node behind it
+        let shortcut = Edges(vec![(0, 1, 1), (0, 5, 2), (2, 1, 3), (1, 1, 3), (3, 1, 4)]);
+        let result = dmap.repair(&shortcut, [1, 3]);
+        assert_eq!(result[&3], (2, 1));
+        assert_eq!(result[&4], (3, 3));
+        assert_eq!(result.len(), 5);
+
+        // 0 -> 1 is gone, 1 is unreachable
+        let unreachable = Edges(vec![(0, 5, 2), (2, 1, 3), (1, 1, 3), (3, 1, 4)]);
+        let result = dmap.repair(&unreachable, [0]).clone();
+        assert!(!result.contains_key(&1));
+        assert_eq!(result[&3], (6, 2));
+        assert_eq!(result[&4], (7, 3));
+    }
+
+    #[test]
+    fn count_all_paths_lattice() {
+        for n in [1, 5, 40, 70] {
+            let lattice = Lattice(n);
+            let mut dmap =
+                DijkstraMap::<_, Vec<(i32, i32)>>::new(&lattice, DijkstraConfig::default());
+            let result = dmap.run((0, (0, 0)));
+            let expected = binomial(2 * n as u32, n as u32);
+
+            let count: num::BigUint =
+                DijkstraMap::<Lattice, Vec<(i32, i32)>>::count_all_paths(&(0, 0), &(n, n), result);
+            assert_eq!(count, expected, "{n}");
+            let count: num::BigUint =
+                DijkstraMap::<Lattice, Vec<(i32, i32)>>::count_all_paths_iterative(
+                    &(0, 0),
+                    &(n, n),
+                    result,
+                );
+            assert_eq!(count, expected, "{n}");
+
+            // binomial(80, 40) overflows u64 but not u128
+            if let Ok(expected) = u128::try_from(&expected) {
+                let count: u128 = DijkstraMap::<Lattice, Vec<(i32, i32)>>::count_all_paths(
+                    &(0, 0),
+                    &(n, n),
+                    result,
+                );
+                assert_eq!(count, expected, "{n}");
+            }
+        }
+    }
+
+    #[test]
+    fn count_all_paths_shares_memo() {
+        let lattice = Lattice(6);
+        let mut dmap = DijkstraMap::<_, Vec<(i32, i32)>>::new(&lattice, DijkstraConfig::default());
+        let result = dmap.run((0, (0, 0)));
+
+        let mut memo = HashMap::new();
+        for r in 0..=6 {
+            for c in 0..=6 {
+                let count: u64 = DijkstraMap::<Lattice, Vec<(i32, i32)>>::count_all_paths_aux(
+                    &(0, 0),
+                    &(r, c),
+                    result,
+                    &mut memo,
+                );
+                assert_eq!(
+                    num::BigUint::from(count),
+                    binomial((r + c) as u32, r as u32)
+                );
+            }
+        }
+        // unreachable from the start
+        let count: u64 =
+            DijkstraMap::<Lattice, Vec<(i32, i32)>>::count_all_paths(&(3, 3), &(0, 0), result);
+        assert_eq!(count, 0);
+        let count: u64 = DijkstraMap::<Lattice, Vec<(i32, i32)>>::count_all_paths_iterative(
+            &(0, 0),
+            &(7, 7),
+            result,
+        );
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn count_all_paths_iterative_long_chain() {
+        let chain = Chain(200_000);
+        let mut dmap = DijkstraMap::<_, Vec<i32>>::new(&chain, DijkstraConfig::default());
+        let result = dmap.run((0, 0));
+        let count: u64 =
+            DijkstraMap::<Chain, Vec<i32>>::count_all_paths_iterative(&0, &199_999, result);
+        assert_eq!(count, 1);
+    }
 }
@@ -0,0 +1,328 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Debug, Write},
+    hash::Hash,
+};
+
+use crate::dijkstra::{DijkstraInput, DijkstraResult, PrevPathSet};
+
+/// What to render, see [`result_to_dot`] and [`adjacency_to_dot`].
+#[derive(Debug, Clone)]
+pub struct DotOptions<I> {
+    /// Path to highlight, e.g. from `DijkstraMap::extract_path`. Its
+    /// nodes are always rendered.
+    pub path: Vec<I>,
+    /// Render at most this many nodes besides the path's, the cheapest
+    /// (or smallest) ones.
+    pub max_nodes: Option<usize>,
+}
+
+impl<I> Default for DotOptions<I> {
+    fn default() -> Self {
+        Self {
+            path: vec![],
+            max_nodes: None,
+        }
+    }
+}
+
+const HIGHLIGHT: &str = "color=red, penwidth=2";
+
+/// `label` as a DOT string literal.
+fn quote(label: &str) -> String {
+    let escaped = label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+/// Picks the nodes to render: every node of the path, then up to
+/// `max_nodes` out of `nodes`, which must be in order of preference.
+/// Returns them along with how many were left out.
+fn keep_nodes<I>(nodes: impl Iterator<Item = I>, options: &DotOptions<I>) -> (Vec<I>, usize)
+where
+    I: Eq + Hash + Copy,
+{
+    let mut seen = HashSet::new();
+    let mut kept = options
+        .path
+        .iter()
+        .copied()
+        .filter(|x| seen.insert(*x))
+        .collect::<Vec<_>>();
+    let mut rest = 0;
+    let mut skipped = 0;
+    for x in nodes {
+        if !seen.insert(x) {
+            continue;
+        }
+        if options.max_nodes.is_some_and(|max| rest >= max) {
+            skipped += 1;
+        } else {
+            kept.push(x);
+            rest += 1;
+        }
+    }
+    (kept, skipped)
+}
+
+/// DOT identifier of every kept node, `n0`, `n1`...
+fn node_ids<I>(kept: &[I]) -> HashMap<I, String>
+where
+    I: Eq + Hash + Copy,
+{
+    kept.iter()
+        .enumerate()
+        .map(|(n, x)| (*x, format!("n{n}")))
+        .collect()
+}
+
+fn path_edges<I>(options: &DotOptions<I>) -> HashSet<(I, I)>
+where
+    I: Eq + Hash + Copy,
+{
+    options
+        .path
+        .windows(2)
+        .map(|step| (step[0], step[1]))
+        .collect()
+}
+
+/// Renders the result of a search as a DOT digraph. Every node is
+/// labelled with `label` and its best cost and has an edge from each
+/// of its previous indexes, starting locations are drawn as boxes.
+pub fn result_to_dot<T, U, L>(
+    data: &DijkstraResult<T, U>,
+    options: &DotOptions<T::Index>,
+    label: L,
+) -> String
+where
+    T: DijkstraInput,
+    T::Cost: Ord + Debug,
+    T::Index: Ord + Hash + Copy,
+    U: PrevPathSet<T::Index>,
+    L: Fn(&T::Index) -> String,
+{
+    let mut by_cost = data.iter().collect::<Vec<_>>();
+    by_cost.sort_by(|(a, (a_cost, _)), (b, (b_cost, _))| (a_cost, a).cmp(&(b_cost, b)));
+    let reached = by_cost.into_iter().map(|(x, _)| *x);
+    let (kept, skipped) = keep_nodes(reached, options);
+    let ids = node_ids(&kept);
+    let on_path = options.path.iter().collect::<HashSet<_>>();
+    let path_edges = path_edges(options);
+
+    let mut dot = String::from("digraph dijkstra {\n");
+    if skipped > 0 {
+        writeln!(dot, "  // {skipped} more nodes not shown").unwrap();
+    }
+    for x in kept.iter() {
+        let Some((cost, prev_idxs)) = data.get(x) else {
+            continue;
+        };
+        let mut attrs = vec![format!(
+            "label={}",
+            quote(&format!("{}\n{cost:?}", label(x)))
+        )];
+        if prev_idxs.indexes().any(|prev| prev == x) {
+            attrs.push("shape=box".to_string());
+        }
+        if on_path.contains(x) {
+            attrs.push(HIGHLIGHT.to_string());
+        }
+        writeln!(dot, "  {} [{}];", ids[x], attrs.join(", ")).unwrap();
+    }
+    for x in kept.iter() {
+        let Some((_, prev_idxs)) = data.get(x) else {
+            continue;
+        };
+        let mut prev_idxs = prev_idxs
+            .indexes()
+            .filter(|prev| *prev != x && ids.contains_key(prev))
+            .collect::<Vec<_>>();
+        prev_idxs.sort();
+        for prev in prev_idxs {
+            write!(dot, "  {} -> {}", ids[prev], ids[x]).unwrap();
+            if path_edges.contains(&(*prev, *x)) {
+                write!(dot, " [{HIGHLIGHT}]").unwrap();
+            }
+            dot.push_str(";\n");
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Renders an adjacency map, e.g. `HashMap<String, Vec<String>>`, as a
+/// DOT digraph with every node labelled with `label`. Nodes only found
+/// as a target are included as well.
+pub fn adjacency_to_dot<I, N, L>(
+    adjacency: &HashMap<I, N>,
+    options: &DotOptions<&I>,
+    label: L,
+) -> String
+where
+    I: Ord + Hash,
+    for<'a> &'a N: IntoIterator<Item = &'a I>,
+    L: Fn(&I) -> String,
+{
+    let mut nodes = adjacency
+        .iter()
+        .flat_map(|(from, to)| std::iter::once(from).chain(to))
+        .collect::<Vec<_>>();
+    nodes.sort();
+    nodes.dedup();
+    let (kept, skipped) = keep_nodes(nodes.into_iter(), options);
+    let ids = node_ids(&kept);
+    let on_path = options.path.iter().collect::<HashSet<_>>();
+    let path_edges = path_edges(options);
+
+    let mut dot = String::from("digraph adjacency {\n");
+    if skipped > 0 {
+        writeln!(dot, "  // {skipped} more nodes not shown").unwrap();
+    }
+    for x in kept.iter() {
+        write!(dot, "  {} [label={}", ids[x], quote(&label(x))).unwrap();
+        if on_path.contains(x) {
+            write!(dot, ", {HIGHLIGHT}").unwrap();
+        }
+        dot.push_str("];\n");
+    }
+    for from in kept.iter() {
+        let Some(to) = adjacency.get(*from) else {
+            continue;
+        };
+        for to in to.into_iter().filter(|to| ids.contains_key(to)) {
+            write!(dot, "  {} -> {}", ids[from], ids[&to]).unwrap();
+            if path_edges.contains(&(*from, to)) {
+                write!(dot, " [{HIGHLIGHT}]").unwrap();
+            }
+            dot.push_str(";\n");
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dijkstra::{DijkstraConfig, DijkstraMap},
+        test_graphs::Edges,
+    };
+
+    fn edges() -> Edges {
+        Edges(vec![(0, 1, 1), (0, 1, 2), (1, 1, 3), (2, 1, 3), (3, 5, 4)])
+    }
+
+    #[test]
+    fn result_with_tied_previous_indexes() {
+        let edges = edges();
+        let mut dmap = DijkstraMap::<_, Vec<i32>>::new(&edges, DijkstraConfig::default());
+        let result = dmap.run((0, 0));
+        let options = DotOptions {
+            path: vec![0, 2, 3],
+            max_nodes: None,
+        };
+        let dot = result_to_dot::<Edges, _, _>(result, &options, |x| x.to_string());
+        assert_eq!(
+            dot,
+            r#"digraph dijkstra {
+  n0 [label="0\n0", shape=box, color=red, penwidth=2];
+  n1 [label="2\n1", color=red, penwidth=2];
+  n2 [label="3\n2", color=red, penwidth=2];
+  n3 [label="1\n1"];
+  n4 [label="4\n7"];
+  n0 -> n1 [color=red, penwidth=2];
+  n3 -> n2;
+  n1 -> n2 [color=red, penwidth=2];
+  n0 -> n3;
+  n2 -> n4;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn result_node_cap() {
+        let edges = edges();
+        let mut dmap = DijkstraMap::<_, i32>::new(&edges, DijkstraConfig::default());
+        let result = dmap.run((0, 0));
+        let options = DotOptions {
+            path: vec![],
+            max_nodes: Some(2),
+        };
+        let dot = result_to_dot::<Edges, _, _>(result, &options, |x| x.to_string());
+        assert_eq!(
+            dot,
+            r#"digraph dijkstra {
+  // 3 more nodes not shown
+  n0 [label="0\n0", shape=box];
+  n1 [label="1\n1"];
+  n0 -> n1;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn path_is_kept_past_the_cap() {
+        let edges = edges();
+        let mut dmap = DijkstraMap::<_, i32>::new(&edges, DijkstraConfig::default());
+        let result = dmap.run((0, 0));
+        let options = DotOptions {
+            path: vec![0, 2, 3, 4],
+            max_nodes: Some(0),
+        };
+        let dot = result_to_dot::<Edges, _, _>(result, &options, |x| format!("node {x}"));
+        assert_eq!(
+            dot,
+            r#"digraph dijkstra {
+  // 1 more nodes not shown
+  n0 [label="node 0\n0", shape=box, color=red, penwidth=2];
+  n1 [label="node 2\n1", color=red, penwidth=2];
+  n2 [label="node 3\n2", color=red, penwidth=2];
+  n3 [label="node 4\n7", color=red, penwidth=2];
+  n0 -> n1 [color=red, penwidth=2];
+  n1 -> n2 [color=red, penwidth=2];
+  n2 -> n3 [color=red, penwidth=2];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn labels_are_escaped() {
+        assert_eq!(quote("say \"hi\"\n\\"), r#""say \"hi\"\n\\""#);
+    }
+
+    #[test]
+    fn adjacency_map() {
+        let adjacency = HashMap::from([
+            (
+                "you".to_string(),
+                vec!["bbb".to_string(), "ccc".to_string()],
+            ),
+            ("bbb".to_string(), vec!["out".to_string()]),
+        ]);
+        let you = "you".to_string();
+        let bbb = "bbb".to_string();
+        let options = DotOptions {
+            path: vec![&you, &bbb],
+            max_nodes: Some(1),
+        };
+        assert_eq!(
+            adjacency_to_dot(&adjacency, &options, |x| x.clone()),
+            r#"digraph adjacency {
+  // 1 more nodes not shown
+  n0 [label="you", color=red, penwidth=2];
+  n1 [label="bbb", color=red, penwidth=2];
+  n2 [label="ccc"];
+  n0 -> n1 [color=red, penwidth=2];
+  n0 -> n2;
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Error;
+use pest::Parser;
+use pest_derive::Parser;
+
+use crate::{
+    bidirectional::ReversibleInput, dense_dijkstra::DenseDijkstraInput, dijkstra::DijkstraInput,
+};
+
+#[derive(Parser)]
+#[grammar_inline = r##"
+    space = _{ " " | "\t" }
+    node = { (ASCII_ALPHANUMERIC | "_")+ }
+    outputs = { node ~ (space+ ~ node)* }
+    line = { node ~ ":" ~ (space+ ~ outputs)? ~ space* }
+    data = { SOI ~ NEWLINE* ~ line ~ (NEWLINE+ ~ line)* ~ WHITE_SPACE* ~ EOI }
+"##]
+struct PestParser;
+
+/// Interned id of a node of a [`Graph`], ids are handed out in order
+/// from `0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(pub usize);
+
+/// Directed graph with string labelled nodes. Every move costs `1`
+/// when searched through [`DijkstraInput`].
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    adjacent: Vec<Vec<NodeId>>,
+    reverse_adjacent: Vec<Vec<NodeId>>,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a graph from `(from, to)` edges.
+    pub fn from_edges<S, E>(edges: E) -> Self
+    where
+        S: AsRef<str>,
+        E: IntoIterator<Item = (S, S)>,
+    {
+        let mut graph = Self::new();
+        for (from, to) in edges {
+            graph.add_edge(from.as_ref(), to.as_ref());
+        }
+        graph
+    }
+
+    /// Parses lines of the form `a: b c d`, an edge from `a` to each
+    /// of `b`, `c` and `d`.
+    pub fn from_lines(input: &str) -> Result<Self, Error> {
+        let mut data = PestParser::parse(Rule::data, input)?;
+        let mut graph = Self::new();
+        for pair in data.next().unwrap().into_inner() {
+            match pair.as_rule() {
+                Rule::line => {
+                    let mut from = None;
+                    for pair in pair.into_inner() {
+                        match pair.as_rule() {
+                            Rule::node => {
+                                from = Some(graph.intern(pair.as_str()));
+                            }
+                            Rule::outputs => {
+                                for pair in pair.into_inner() {
+                                    let to = graph.intern(pair.as_str());
+                                    graph.add_edge_ids(from.unwrap(), to);
+                                }
+                            }
+                            _ => unreachable!(),
+                        }
+                    }
+                }
+                Rule::EOI => (),
+                _ => unreachable!(),
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Returns the id of `name`, adding it as a new node if needed.
+    pub fn intern(&mut self, name: &str) -> NodeId {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        let id = NodeId(self.names.len());
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.adjacent.push(vec![]);
+        self.reverse_adjacent.push(vec![]);
+        id
+    }
+
+    /// Adds an edge, adding its nodes as needed.
+    pub fn add_edge(&mut self, from: &str, to: &str) {
+        let from = self.intern(from);
+        let to = self.intern(to);
+        self.add_edge_ids(from, to);
+    }
+
+    /// Same as `add_edge` for nodes that are already interned.
+    pub fn add_edge_ids(&mut self, from: NodeId, to: NodeId) {
+        self.adjacent[from.0].push(to);
+        self.reverse_adjacent[to.0].push(from);
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id.0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Every node id, in order.
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> + use<> {
+        (0..self.len()).map(NodeId)
+    }
+
+    /// Nodes with an edge from `id`.
+    pub fn adjacent(&self, id: NodeId) -> &[NodeId] {
+        &self.adjacent[id.0]
+    }
+
+    /// Nodes with an edge to `id`.
+    pub fn reverse_adjacent(&self, id: NodeId) -> &[NodeId] {
+        &self.reverse_adjacent[id.0]
+    }
+
+    pub fn out_degree(&self, id: NodeId) -> usize {
+        self.adjacent[id.0].len()
+    }
+
+    pub fn in_degree(&self, id: NodeId) -> usize {
+        self.reverse_adjacent[id.0].len()
+    }
+
+    /// Nodes that can be reached from `start`, itself included.
+    pub fn reachable_from(&self, start: NodeId) -> HashSet<NodeId> {
+        self.walk(start, |_| true, Self::adjacent)
+    }
+
+    /// Nodes that can reach `end`, itself included.
+    pub fn reaching(&self, end: NodeId) -> HashSet<NodeId> {
+        self.walk(end, |_| true, Self::reverse_adjacent)
+    }
+
+    /// Nodes reached from `start` following `next`, only stepping on
+    /// the ones allowed by `allow`.
+    pub(crate) fn walk<'a, A, F>(&'a self, start: NodeId, allow: A, next: F) -> HashSet<NodeId>
+    where
+        A: Fn(NodeId) -> bool,
+        F: Fn(&'a Self, NodeId) -> &'a [NodeId],
+    {
+        let mut seen = HashSet::new();
+        if !allow(start) {
+            return seen;
+        }
+        let mut stack = vec![start];
+        seen.insert(start);
+        while let Some(x) = stack.pop() {
+            for y in next(self, x) {
+                if allow(*y) && seen.insert(*y) {
+                    stack.push(*y);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Copy of the graph with only the nodes matching `keep` and the
+    /// edges between them. Ids are handed out again, names are kept.
+    pub fn subgraph<K>(&self, keep: K) -> Self
+    where
+        K: Fn(NodeId) -> bool,
+    {
+        let mut graph = Self::new();
+        for from in self.nodes().filter(|x| keep(*x)) {
+            let from_id = graph.intern(self.name(from));
+            for to in self.adjacent(from).iter().filter(|to| keep(**to)) {
+                let to_id = graph.intern(self.name(*to));
+                graph.add_edge_ids(from_id, to_id);
+            }
+        }
+        graph
+    }
+}
+
+impl DijkstraInput for Graph {
+    type Cost = u64;
+    type Index = NodeId;
+
+    fn for_each_adjacent<F>(&self, x: &Self::Index, mut f: F)
+    where
+        F: FnMut(Self::Cost, Self::Index),
+    {
+        for to in self.adjacent(*x) {
+            f(1, *to);
+        }
+    }
+}
+
+impl ReversibleInput for Graph {
+    fn for_each_reverse_adjacent<F>(&self, x: &Self::Index, mut f: F)
+    where
+        F: FnMut(Self::Cost, Self::Index),
+    {
+        for from in self.reverse_adjacent(*x) {
+            f(1, *from);
+        }
+    }
+}
+
+impl DenseDijkstraInput for Graph {
+    fn num_indices(&self) -> usize {
+        self.len()
+    }
+
+    fn index_to_usize(&self, x: &Self::Index) -> usize {
+        x.0
+    }
+
+    fn usize_to_index(&self, x: usize) -> Self::Index {
+        NodeId(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bfs::bfs,
+        dijkstra::{DijkstraConfig, DijkstraMap},
+    };
+
+    const EXAMPLE: &str = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+";
+
+    #[test]
+    fn from_lines() {
+        let graph = Graph::from_lines(EXAMPLE).unwrap();
+        assert_eq!(graph.len(), 11);
+        let id = |name| graph.id(name).unwrap();
+        assert_eq!(graph.name(id("ccc")), "ccc");
+        assert_eq!(
+            graph.adjacent(id("ccc")),
+            &[id("ddd"), id("eee"), id("fff")]
+        );
+        assert_eq!(graph.out_degree(id("ccc")), 3);
+        assert_eq!(graph.in_degree(id("out")), 4);
+        assert_eq!(graph.out_degree(id("out")), 0);
+        let mut into_ccc = graph.reverse_adjacent(id("ccc")).to_vec();
+        into_ccc.sort();
+        assert_eq!(into_ccc, vec![id("you"), id("hhh")]);
+        assert_eq!(graph.id("zzz"), None);
+    }
+
+    #[test]
+    fn from_lines_rejects_garbage() {
+        assert!(Graph::from_lines("aaa bbb\n").is_err());
+    }
+
+    #[test]
+    fn from_edges_matches_from_lines() {
+        let graph = Graph::from_edges([("a", "b"), ("a", "c"), ("c", "a")]);
+        let parsed = Graph::from_lines("a: b c\nc: a\n").unwrap();
+        for id in graph.nodes() {
+            assert_eq!(graph.name(id), parsed.name(id));
+            assert_eq!(graph.adjacent(id), parsed.adjacent(id));
+            assert_eq!(graph.reverse_adjacent(id), parsed.reverse_adjacent(id));
+        }
+    }
+
+    #[test]
+    fn searches_count_edges() {
+        let graph = Graph::from_lines(EXAMPLE).unwrap();
+        let (you, out) = (graph.id("you").unwrap(), graph.id("out").unwrap());
+
+        let mut dmap = DijkstraMap::<_, NodeId>::new(&graph, DijkstraConfig::default());
+        let result = dmap.run((0, you));
+        assert_eq!(result[&out].0, 3);
+        assert_eq!(bfs::<_, NodeId>(&graph, (0, you))[&out].0, 3);
+        assert!(!result.contains_key(&graph.id("aaa").unwrap()));
+    }
+}
@@ -0,0 +1,204 @@
+use std::{collections::HashSet, fmt::Debug, hash::Hash, ops::Add};
+
+use crate::dijkstra::{DijkstraConfig, DijkstraInput, DijkstraMap};
+
+/// Wraps an input for a spur search, hiding the nodes of the root path
+/// and the edges already taken by earlier paths sharing that root.
+struct SpurInput<'a, T>
+where
+    T: DijkstraInput,
+{
+    dijkstra_input: &'a T,
+    removed_nodes: HashSet<T::Index>,
+    removed_edges: HashSet<(T::Index, T::Index)>,
+}
+
+impl<T> DijkstraInput for SpurInput<'_, T>
+where
+    T: DijkstraInput,
+    T::Index: Eq + Hash + Copy,
+{
+    type Cost = T::Cost;
+    type Index = T::Index;
+
+    fn for_each_adjacent<F>(&self, x: &Self::Index, mut f: F)
+    where
+        F: FnMut(Self::Cost, Self::Index),
+    {
+        self.dijkstra_input.for_each_adjacent(x, |cost, index| {
+            if !self.removed_nodes.contains(&index) && !self.removed_edges.contains(&(*x, index)) {
+                f(cost, index);
+            }
+        });
+    }
+}
+
+/// A path found by [`KShortestPaths`], every index along with the
+/// cost of reaching it.
+type CostedPath<T> = Vec<(<T as DijkstraInput>::Cost, <T as DijkstraInput>::Index)>;
+
+/// Iterator over the loopless paths from a start to an end in order
+/// of increasing cost (Yen's algorithm). Each item is the total cost
+/// and the path from start to end, both included. Paths are only
+/// computed as they are asked for.
+pub struct KShortestPaths<'a, T>
+where
+    T: DijkstraInput,
+{
+    dijkstra_input: &'a T,
+    start: (T::Cost, T::Index),
+    end: T::Index,
+    found: Vec<CostedPath<T>>,
+    candidates: Vec<CostedPath<T>>,
+    started: bool,
+}
+
+impl<'a, T> KShortestPaths<'a, T>
+where
+    T: DijkstraInput,
+    T::Cost: Ord + Eq + PartialEq + Debug + Add<Output = T::Cost> + Clone + Copy,
+    T::Index: Eq + PartialEq + PartialOrd + Debug + Hash + Clone + Copy,
+    (T::Cost, T::Index): Ord,
+{
+    pub fn new(dijkstra_input: &'a T, start: (T::Cost, T::Index), end: T::Index) -> Self {
+        Self {
+            dijkstra_input,
+            start,
+            end,
+            found: vec![],
+            candidates: vec![],
+            started: false,
+        }
+    }
+
+    /// Best path from `start` to `end` on `input`, with the cost
+    /// of every step.
+    fn best_path<I>(input: &I, start: (T::Cost, T::Index), end: T::Index) -> Option<CostedPath<T>>
+    where
+        I: DijkstraInput<Cost = T::Cost, Index = T::Index>,
+    {
+        let mut dmap = DijkstraMap::<_, T::Index>::new(input, DijkstraConfig::default());
+        let (goal, visited) = dmap.run_until(start, |idx| *idx == end);
+        goal?;
+        let path = DijkstraMap::<I, T::Index>::extract_path(&start.1, &end, visited).ok()?;
+        Some(path.into_iter().map(|idx| (visited[&idx].0, idx)).collect())
+    }
+
+    /// Adds every spur path deviating from the last found path to the
+    /// candidates.
+    fn find_candidates(&mut self) {
+        let last = self.found.last().unwrap().clone();
+        for spur in 0..last.len() - 1 {
+            let root = &last[..=spur];
+            let spur_node = root[spur];
+
+            let removed_edges = self
+                .found
+                .iter()
+                .filter(|path| path.len() > spur + 1 && path[..=spur] == *root)
+                .map(|path| (path[spur].1, path[spur + 1].1))
+                .collect();
+            let removed_nodes = root[..spur].iter().map(|(_, idx)| *idx).collect();
+            let spur_input = SpurInput {
+                dijkstra_input: self.dijkstra_input,
+                removed_nodes,
+                removed_edges,
+            };
+
+            if let Some(spur_path) = Self::best_path(&spur_input, spur_node, self.end) {
+                let mut candidate = root[..spur].to_vec();
+                candidate.extend(spur_path);
+                if !self.candidates.contains(&candidate) && !self.found.contains(&candidate) {
+                    self.candidates.push(candidate);
+                }
+            }
+        }
+    }
+}
+
+impl<T> Iterator for KShortestPaths<'_, T>
+where
+    T: DijkstraInput,
+    T::Cost: Ord + Eq + PartialEq + Debug + Add<Output = T::Cost> + Clone + Copy,
+    T::Index: Eq + PartialEq + PartialOrd + Debug + Hash + Clone + Copy,
+    (T::Cost, T::Index): Ord,
+{
+    type Item = (T::Cost, Vec<T::Index>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            self.candidates
+                .extend(Self::best_path(self.dijkstra_input, self.start, self.end));
+        } else if !self.found.is_empty() {
+            self.find_candidates();
+        }
+
+        let (best, _) = self
+            .candidates
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, path)| path.last().unwrap().0)?;
+        let path = self.candidates.swap_remove(best);
+        self.found.push(path.clone());
+
+        let cost = path.last().unwrap().0;
+        Some((cost, path.into_iter().map(|(_, idx)| idx).collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dijkstra::AdjacencyList;
+
+    /// The example graph from the Wikipedia page on Yen's algorithm.
+    struct Example;
+
+    impl AdjacencyList for Example {
+        type Cost = i32;
+        type Index = char;
+
+        fn get_adjacent(&self, x: &Self::Index) -> Vec<(Self::Cost, Self::Index)> {
+            match x {
+                'C' => vec![(3, 'D'), (2, 'E')],
+                'D' => vec![(4, 'F')],
+                'E' => vec![(1, 'D'), (2, 'F'), (3, 'G')],
+                'F' => vec![(2, 'G'), (1, 'H')],
+                'G' => vec![(2, 'H')],
+                _ => vec![],
+            }
+        }
+    }
+
+    #[test]
+    fn yen_example() {
+        let paths = KShortestPaths::new(&Example, (0, 'C'), 'H').collect::<Vec<_>>();
+
+        let costs = paths.iter().map(|(cost, _)| *cost).collect::<Vec<_>>();
+        assert_eq!(costs, vec![5, 7, 8, 8, 8, 11, 11]);
+
+        let mut paths = paths
+            .into_iter()
+            .map(|(_, path)| path.into_iter().collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(paths[0], "CEFH");
+        assert_eq!(paths[1], "CEGH");
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec!["CDFGH", "CDFH", "CEDFGH", "CEDFH", "CEFGH", "CEFH", "CEGH"]
+        );
+    }
+
+    #[test]
+    fn yen_unreachable() {
+        assert_eq!(KShortestPaths::new(&Example, (0, 'H'), 'C').next(), None);
+    }
+
+    #[test]
+    fn yen_start_is_end() {
+        let paths = KShortestPaths::new(&Example, (0, 'C'), 'C').collect::<Vec<_>>();
+        assert_eq!(paths, vec![(0, vec!['C'])]);
+    }
+}
@@ -1 +1,18 @@
+pub mod all_pairs;
+pub mod answers;
+pub mod bellman_ford;
+pub mod bfs;
+pub mod bidirectional;
+pub mod dag;
+pub mod days;
+pub mod dense_dijkstra;
 pub mod dijkstra;
+pub mod dot;
+pub mod graph;
+pub mod k_shortest_paths;
+pub mod scc;
+#[cfg(test)]
+pub(crate) mod test_graphs;
+pub mod timing;
+pub mod union_find;
+pub mod waypoints;
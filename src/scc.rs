@@ -0,0 +1,241 @@
+use std::collections::HashSet;
+
+use crate::graph::{Graph, NodeId};
+
+/// Strongly connected components of a [`Graph`], numbered in
+/// topological order: every edge between two components goes from a
+/// lower number to a higher one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    component_of: Vec<usize>,
+    members: Vec<Vec<NodeId>>,
+}
+
+impl Components {
+    /// Number of components.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// The component `id` belongs to.
+    pub fn component(&self, id: NodeId) -> usize {
+        self.component_of[id.0]
+    }
+
+    /// The nodes of component `c`, in id order.
+    pub fn members(&self, c: usize) -> &[NodeId] {
+        &self.members[c]
+    }
+
+    /// Whether the graph has no cycle, i.e. every component is a single
+    /// node without an edge to itself.
+    pub fn is_acyclic(&self, graph: &Graph) -> bool {
+        self.members
+            .iter()
+            .all(|members| members.len() == 1 && !graph.adjacent(members[0]).contains(&members[0]))
+    }
+}
+
+/// Tarjan's algorithm, with an explicit stack so deep graphs do not
+/// overflow the call stack.
+pub fn strongly_connected_components(graph: &Graph) -> Components {
+    let n = graph.len();
+    let mut index = vec![usize::MAX; n];
+    let mut low_link = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = vec![];
+    let mut next_index = 0;
+    let mut found = vec![];
+
+    for root in graph.nodes() {
+        if index[root.0] != usize::MAX {
+            continue;
+        }
+        // each entry is a node being visited and its next edge to follow
+        let mut calls = vec![(root, 0)];
+        index[root.0] = next_index;
+        low_link[root.0] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root.0] = true;
+
+        while let Some((v, edge)) = calls.last_mut() {
+            let v = *v;
+            if let Some(w) = graph.adjacent(v).get(*edge).copied() {
+                *edge += 1;
+                if index[w.0] == usize::MAX {
+                    index[w.0] = next_index;
+                    low_link[w.0] = next_index;
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack[w.0] = true;
+                    calls.push((w, 0));
+                } else if on_stack[w.0] {
+                    low_link[v.0] = low_link[v.0].min(index[w.0]);
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some((u, _)) = calls.last() {
+                low_link[u.0] = low_link[u.0].min(low_link[v.0]);
+            }
+            if low_link[v.0] == index[v.0] {
+                let mut members = vec![];
+                loop {
+                    let w = stack.pop().unwrap();
+                    on_stack[w.0] = false;
+                    members.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                members.sort();
+                found.push(members);
+            }
+        }
+    }
+
+    // Tarjan finds the components in reverse topological order
+    found.reverse();
+    let mut component_of = vec![0; n];
+    for (c, members) in found.iter().enumerate() {
+        for x in members {
+            component_of[x.0] = c;
+        }
+    }
+    Components {
+        component_of,
+        members: found,
+    }
+}
+
+/// The DAG of the components, node `NodeId(c)` standing for component
+/// `c` and named after its members joined with `,`. Edges inside a
+/// component are dropped and parallel ones merged.
+pub fn condensation(graph: &Graph, components: &Components) -> Graph {
+    let mut condensed = Graph::new();
+    for c in 0..components.len() {
+        let name = components
+            .members(c)
+            .iter()
+            .map(|x| graph.name(*x))
+            .collect::<Vec<_>>()
+            .join(",");
+        condensed.intern(&name);
+    }
+
+    let mut seen = HashSet::new();
+    for from in graph.nodes() {
+        for to in graph.adjacent(from) {
+            let edge = (components.component(from), components.component(*to));
+            if edge.0 != edge.1 && seen.insert(edge) {
+                condensed.add_edge_ids(NodeId(edge.0), NodeId(edge.1));
+            }
+        }
+    }
+    condensed
+}
+
+/// Copy of the graph with only the nodes that can reach `end`, e.g.
+/// before counting the paths ending there.
+pub fn prune_to_reaching(graph: &Graph, end: NodeId) -> Graph {
+    let reaching = graph.reaching(end);
+    graph.subgraph(|x| reaching.contains(&x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dag::topological_sort;
+
+    // {a, b, c} and {d, e} are cycles, f and g hang off them and h
+    // can not reach out
+    const EXAMPLE: &str = "\
+a: b
+b: c
+c: a d
+d: e
+e: d f
+f: out
+g: a
+h: h
+";
+
+    fn names(graph: &Graph, members: &[NodeId]) -> Vec<String> {
+        members.iter().map(|x| graph.name(*x).to_string()).collect()
+    }
+
+    #[test]
+    fn finds_components_in_topological_order() {
+        let graph = Graph::from_lines(EXAMPLE).unwrap();
+        let components = strongly_connected_components(&graph);
+        assert_eq!(components.len(), 6);
+        assert!(!components.is_acyclic(&graph));
+
+        let id = |name| graph.id(name).unwrap();
+        let abc = components.component(id("a"));
+        assert_eq!(names(&graph, components.members(abc)), ["a", "b", "c"]);
+        let de = components.component(id("d"));
+        assert_eq!(names(&graph, components.members(de)), ["d", "e"]);
+        assert_eq!(
+            names(&graph, components.members(components.component(id("h")))),
+            ["h"]
+        );
+
+        for from in graph.nodes() {
+            for to in graph.adjacent(from) {
+                assert!(components.component(from) <= components.component(*to));
+            }
+        }
+    }
+
+    #[test]
+    fn condensation_is_a_dag() {
+        let graph = Graph::from_lines(EXAMPLE).unwrap();
+        let components = strongly_connected_components(&graph);
+        let condensed = condensation(&graph, &components);
+        assert_eq!(condensed.len(), components.len());
+
+        let order = topological_sort(&condensed).unwrap();
+        assert_eq!(order.len(), condensed.len());
+        let abc = condensed.id("a,b,c").unwrap();
+        let de = condensed.id("d,e").unwrap();
+        assert_eq!(condensed.adjacent(abc), &[de]);
+        assert_eq!(condensed.in_degree(abc), 1);
+        assert!(strongly_connected_components(&condensed).is_acyclic(&condensed));
+    }
+
+    #[test]
+    fn acyclic_graph() {
+        let graph = Graph::from_lines("a: b c\nb: d\nc: d\n").unwrap();
+        let components = strongly_connected_components(&graph);
+        assert_eq!(components.len(), 4);
+        assert!(components.is_acyclic(&graph));
+    }
+
+    #[test]
+    fn prunes_nodes_not_reaching_the_end() {
+        let graph = Graph::from_lines(EXAMPLE).unwrap();
+        let pruned = prune_to_reaching(&graph, graph.id("out").unwrap());
+        let mut kept = pruned.nodes().map(|x| pruned.name(x)).collect::<Vec<_>>();
+        kept.sort();
+        assert_eq!(kept, ["a", "b", "c", "d", "e", "f", "g", "out"]);
+        let c = pruned.id("c").unwrap();
+        assert_eq!(pruned.out_degree(c), 2);
+    }
+
+    #[test]
+    fn deep_chain() {
+        let edges = (0..100_000).map(|n| (n.to_string(), (n + 1).to_string()));
+        let mut graph = Graph::from_edges(edges);
+        graph.add_edge("100000", "0");
+        let components = strongly_connected_components(&graph);
+        assert_eq!(components.len(), 1);
+        assert_eq!(components.members(0).len(), 100_001);
+    }
+}
@@ -0,0 +1,148 @@
+//! Graphs shared by the tests of the search modules.
+
+use std::{collections::HashMap, fmt::Debug, hash::Hash};
+
+use crate::{
+    bidirectional::{ReverseAdjacencyList, ReversibleInput},
+    dense_dijkstra::DenseDijkstraInput,
+    dijkstra::{AdjacencyList, DijkstraInput},
+};
+
+/// Directed graph given as `(from, cost, to)` edges.
+pub(crate) struct Edges(pub(crate) Vec<(i32, i32, i32)>);
+
+impl AdjacencyList for Edges {
+    type Cost = i32;
+    type Index = i32;
+
+    fn get_adjacent(&self, x: &Self::Index) -> Vec<(Self::Cost, Self::Index)> {
+        self.0
+            .iter()
+            .filter(|(from, _, _)| from == x)
+            .map(|(_, cost, to)| (*cost, *to))
+            .collect()
+    }
+}
+
+impl ReverseAdjacencyList for Edges {
+    fn get_reverse_adjacent(&self, x: &Self::Index) -> Vec<(Self::Cost, Self::Index)> {
+        self.0
+            .iter()
+            .filter(|(_, _, to)| to == x)
+            .map(|(from, cost, _)| (*cost, *from))
+            .collect()
+    }
+}
+
+/// Weighted grid, moving onto a cell costs the cell's digit and `#`
+/// cells are walls.
+#[derive(Debug, Clone)]
+pub(crate) struct Grid {
+    cells: Vec<Vec<Option<i32>>>,
+}
+
+impl Grid {
+    pub(crate) fn parse(rows: &[&str]) -> Self {
+        let cells = rows
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .map(|cell| cell.to_digit(10).map(|cost| cost as i32))
+                    .collect()
+            })
+            .collect();
+        Self { cells }
+    }
+
+    /// Cost of moving onto `(r, c)`, `None` for walls and cells off
+    /// the grid.
+    pub(crate) fn cost(&self, (r, c): (i32, i32)) -> Option<i32> {
+        *self.cells.get(r as usize)?.get(c as usize)?
+    }
+
+    /// Makes moving onto `(r, c)` cost `cost`, `None` for a wall.
+    pub(crate) fn set_cost(&mut self, (r, c): (i32, i32), cost: Option<i32>) {
+        self.cells[r as usize][c as usize] = cost;
+    }
+
+    /// The bottom right corner.
+    pub(crate) fn goal(&self) -> (i32, i32) {
+        (self.cells.len() as i32 - 1, self.cells[0].len() as i32 - 1)
+    }
+
+    fn neighbors((r, c): (i32, i32)) -> [(i32, i32); 4] {
+        [(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)]
+    }
+}
+
+impl DijkstraInput for Grid {
+    type Cost = i32;
+    type Index = (i32, i32);
+
+    fn for_each_adjacent<F>(&self, x: &Self::Index, mut f: F)
+    where
+        F: FnMut(Self::Cost, Self::Index),
+    {
+        for n in Self::neighbors(*x) {
+            if let Some(cost) = self.cost(n) {
+                f(cost, n);
+            }
+        }
+    }
+}
+
+impl ReversibleInput for Grid {
+    fn for_each_reverse_adjacent<F>(&self, x: &Self::Index, mut f: F)
+    where
+        F: FnMut(Self::Cost, Self::Index),
+    {
+        let Some(cost) = self.cost(*x) else {
+            return;
+        };
+        for n in Self::neighbors(*x) {
+            if self.cost(n).is_some() {
+                f(cost, n);
+            }
+        }
+    }
+}
+
+impl DenseDijkstraInput for Grid {
+    fn num_indices(&self) -> usize {
+        self.cells.len() * self.cells[0].len()
+    }
+
+    fn index_to_usize(&self, &(r, c): &Self::Index) -> usize {
+        r as usize * self.cells[0].len() + c as usize
+    }
+
+    fn usize_to_index(&self, n: usize) -> Self::Index {
+        let width = self.cells[0].len();
+        ((n / width) as i32, (n % width) as i32)
+    }
+}
+
+/// Everything [`DijkstraInput::for_each_adjacent`] yields for `x`.
+pub(crate) fn adjacent<T: DijkstraInput>(input: &T, x: &T::Index) -> Vec<(T::Cost, T::Index)> {
+    let mut adjacent = vec![];
+    input.for_each_adjacent(x, |cost, index| adjacent.push((cost, index)));
+    adjacent
+}
+
+/// Checks two results collecting every best path agree, whatever the
+/// order the tied previous indexes were found in.
+pub(crate) fn assert_same<I: Eq + Hash + Ord + Debug + Copy>(
+    result: &HashMap<I, (i32, Vec<I>)>,
+    expected: &HashMap<I, (i32, Vec<I>)>,
+) {
+    assert_eq!(result.len(), expected.len());
+    for (idx, (cost, prev)) in result.iter() {
+        let (expected_cost, expected_prev) = &expected[idx];
+        assert_eq!(cost, expected_cost, "{idx:?}");
+        let mut prev = prev.clone();
+        let mut expected_prev = expected_prev.clone();
+        prev.sort();
+        expected_prev.sort();
+        assert_eq!(prev, expected_prev, "{idx:?}");
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    convert::Infallible,
+    fmt::{self, Display, Write},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// What was timed: parsing a day's input or solving one of its parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part(usize),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+/// Spread of the durations of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Stats of `samples`, which must not be empty. The median of an
+    /// even number of samples is the mean of the middle two.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        Self {
+            iterations: n,
+            min: samples[0],
+            median,
+            max: samples[n - 1],
+        }
+    }
+}
+
+/// Runs `f` `iterations` times and returns the spread of its durations.
+pub fn time<T, F>(iterations: usize, mut f: F) -> Stats
+where
+    F: FnMut() -> T,
+{
+    let Ok((stats, _)) = try_time(iterations, || Ok::<_, Infallible>(f()));
+    stats
+}
+
+/// Like [`time`] for an `f` that can fail, stopping at its first error.
+/// Every run is a sample, and the last result is returned with the
+/// stats.
+pub fn try_time<T, E, F>(iterations: usize, mut f: F) -> Result<(Stats, T), E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    let mut samples = Vec::with_capacity(iterations);
+    let mut last = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let x = black_box(f()?);
+        samples.push(start.elapsed());
+        last = Some(x);
+    }
+    let stats = Stats::from_samples(&mut samples);
+    Ok((stats, last.expect("no samples")))
+}
+
+/// One row of a benchmark report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub day: usize,
+    pub step: Step,
+    pub stats: Stats,
+}
+
+/// Report as a JSON array with one object per timing, durations in
+/// nanoseconds.
+pub fn to_json(timings: &[Timing]) -> String {
+    let mut json = String::from("[\n");
+    for (n, timing) in timings.iter().enumerate() {
+        let stats = timing.stats;
+        write!(
+            json,
+            "  {{\"day\": {}, \"step\": \"{}\", \"iterations\": {}, \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+            timing.day,
+            timing.step,
+            stats.iterations,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.max.as_nanos(),
+        )
+        .unwrap();
+        json.push_str(if n + 1 < timings.len() { ",\n" } else { "\n" });
+    }
+    json.push_str("]\n");
+    json
+}
+
+/// Report as CSV with a header line, durations in nanoseconds.
+pub fn to_csv(timings: &[Timing]) -> String {
+    let mut csv = String::from("day,step,iterations,min_ns,median_ns,max_ns\n");
+    for timing in timings {
+        let stats = timing.stats;
+        writeln!(
+            csv,
+            "{},{},{},{},{},{}",
+            timing.day,
+            timing.step,
+            stats.iterations,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.max.as_nanos(),
+        )
+        .unwrap();
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(x: u64) -> Duration {
+        Duration::from_millis(x)
+    }
+
+    #[test]
+    fn stats_of_samples() {
+        let stats = Stats::from_samples(&mut [ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            stats,
+            Stats {
+                iterations: 3,
+                min: ms(1),
+                median: ms(3),
+                max: ms(5),
+            }
+        );
+
+        let stats = Stats::from_samples(&mut [ms(4), ms(1), ms(2), ms(9)]);
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.max, ms(9));
+    }
+
+    #[test]
+    fn times_every_iteration() {
+        let mut calls = 0;
+        let stats = time(4, || calls += 1);
+        assert_eq!(calls, 4);
+        assert_eq!(stats.iterations, 4);
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+    }
+
+    #[test]
+    fn try_time_stops_at_the_first_error() {
+        let mut calls = 0;
+        let (stats, last) = try_time(3, || {
+            calls += 1;
+            Ok::<_, ()>(calls)
+        })
+        .unwrap();
+        assert_eq!((stats.iterations, last), (3, 3));
+
+        calls = 0;
+        let result = try_time(3, || {
+            calls += 1;
+            if calls == 2 { Err(calls) } else { Ok(()) }
+        });
+        assert_eq!(result.unwrap_err(), 2);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn reports() {
+        let stats = Stats {
+            iterations: 2,
+            min: ms(1),
+            median: ms(2),
+            max: ms(3),
+        };
+        let timings = [
+            Timing {
+                day: 7,
+                step: Step::Parse,
+                stats,
+            },
+            Timing {
+                day: 7,
+                step: Step::Part(2),
+                stats,
+            },
+        ];
+        assert_eq!(
+            to_json(&timings),
+            r#"[
+  {"day": 7, "step": "parse", "iterations": 2, "min_ns": 1000000, "median_ns": 2000000, "max_ns": 3000000},
+  {"day": 7, "step": "part2", "iterations": 2, "min_ns": 1000000, "median_ns": 2000000, "max_ns": 3000000}
+]
+"#
+        );
+        assert_eq!(
+            to_csv(&timings),
+            "day,step,iterations,min_ns,median_ns,max_ns\n\
+             7,parse,2,1000000,2000000,3000000\n\
+             7,part2,2,1000000,2000000,3000000\n"
+        );
+        assert_eq!(to_json(&[]), "[\n]\n");
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+/// Disjoint sets over `0..len`, with path compression and union by
+/// size.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// Every element starts in a set of its own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            count: len,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Representative of the set holding `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let root = self.root(x);
+        // point everything on the way straight at the root
+        let mut cur = x;
+        while self.parent[cur] != root {
+            cur = std::mem::replace(&mut self.parent[cur], root);
+        }
+        root
+    }
+
+    /// Same as `find` without compressing the path.
+    fn root(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets holding `a` and `b`, returns false if they were
+    /// already the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (big, small) = if self.size[a] >= self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+        self.count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Size of the set holding `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Size of every set.
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> {
+        (0..self.len())
+            .filter(|x| self.parent[*x] == *x)
+            .map(|x| self.size[x])
+    }
+
+    /// The elements of every set, each in increasing order.
+    pub fn components(&self) -> impl Iterator<Item = Vec<usize>> + use<> {
+        let mut by_root: HashMap<usize, Vec<usize>> = HashMap::new();
+        for x in 0..self.len() {
+            by_root.entry(self.root(x)).or_default().push(x);
+        }
+        by_root.into_values()
+    }
+
+    /// Kruskal-style merging: unions the pairs in the order given until
+    /// only `components` sets are left. Returns the pair whose union
+    /// got there, or `None` if the pairs ran out first.
+    pub fn merge_until<I>(&mut self, pairs: I, components: usize) -> Option<(usize, usize)>
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        if self.count <= components {
+            return None;
+        }
+        pairs
+            .into_iter()
+            .find(|(a, b)| self.union(*a, *b) && self.count <= components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unions_and_sizes() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.count(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert_eq!(sets.count(), 3);
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.size_of(2), 4);
+
+        let mut sizes = sets.component_sizes().collect::<Vec<_>>();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 1, 4]);
+
+        let mut components = sets.components().collect::<Vec<_>>();
+        components.sort();
+        assert_eq!(components, vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn long_chain_is_compressed() {
+        let mut sets = UnionFind::new(100_000);
+        for x in 1..100_000 {
+            sets.union(x - 1, x);
+        }
+        assert_eq!(sets.count(), 1);
+        let root = sets.find(99_999);
+        assert!((0..100_000).all(|x| sets.find(x) == root));
+        assert!((0..100_000).all(|x| sets.parent[x] == root));
+    }
+
+    #[test]
+    fn merge_until_components() {
+        let pairs = [(0, 1), (1, 0), (2, 3), (0, 2), (4, 5), (3, 5)];
+
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.merge_until(pairs, 3), Some((0, 2)));
+        assert_eq!(sets.count(), 3);
+
+        // carries on from where it stopped
+        assert_eq!(
+            sets.merge_until(pairs[4..].iter().copied(), 1),
+            Some((3, 5))
+        );
+        assert_eq!(sets.merge_until(pairs, 1), None);
+
+        let mut sets = UnionFind::new(7);
+        assert_eq!(sets.merge_until(pairs, 1), None);
+        assert_eq!(sets.count(), 2);
+    }
+}
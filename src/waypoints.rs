@@ -0,0 +1,219 @@
+use std::{fmt::Debug, hash::Hash, ops::Add};
+
+use num::Zero;
+
+use crate::dijkstra::{DijkstraConfig, DijkstraInput, DijkstraMap};
+
+/// Best route found by [`shortest_tour`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaypointTour<C, I> {
+    /// Total cost from start to end.
+    pub cost: C,
+    /// The waypoints in the order they are visited.
+    pub order: Vec<I>,
+    /// Every node from start to end, both included.
+    pub path: Vec<I>,
+}
+
+/// Most waypoints [`shortest_tour`] accepts, so that every set of
+/// waypoints fits in the bits of a mask.
+pub const MAX_WAYPOINTS: usize = 31;
+
+/// Cheapest route from `start` to `end` passing through every one of
+/// `waypoints` in any order. Costs between the waypoints come from a
+/// [`DijkstraMap`] run from the start and from each waypoint, the best
+/// order from the Held–Karp bitmask DP, so this is only practical for
+/// about twenty waypoints. Returns `None` if no such route exists or
+/// if there are more than [`MAX_WAYPOINTS`] waypoints.
+pub fn shortest_tour<T>(
+    dijkstra_input: &T,
+    start: T::Index,
+    end: T::Index,
+    waypoints: &[T::Index],
+) -> Option<WaypointTour<T::Cost, T::Index>>
+where
+    T: DijkstraInput,
+    T::Cost: Ord + Debug + Zero + Add<Output = T::Cost> + Copy,
+    T::Index: Eq + PartialOrd + Debug + Hash + Copy,
+    (T::Cost, T::Index): Ord,
+{
+    let n = waypoints.len();
+    if n > MAX_WAYPOINTS {
+        return None;
+    }
+
+    // searches[0] starts at `start`, searches[i + 1] at waypoints[i]
+    let searches = std::iter::once(start)
+        .chain(waypoints.iter().copied())
+        .map(|from| {
+            let mut dmap =
+                DijkstraMap::<_, T::Index>::new(dijkstra_input, DijkstraConfig::default());
+            dmap.run((T::Cost::zero(), from));
+            dmap.into_visited()
+        })
+        .collect::<Vec<_>>();
+    let cost = |search: usize, to: &T::Index| searches[search].get(to).map(|(cost, _)| *cost);
+
+    // best[mask * n + last] is the cheapest way to visit the waypoints
+    // in `mask` starting from `start` and ending at waypoint `last`
+    let mut best: Vec<Option<(T::Cost, usize)>> = vec![None; (1 << n) * n];
+    for (last, waypoint) in waypoints.iter().enumerate() {
+        best[(1 << last) * n + last] = cost(0, waypoint).map(|cost| (cost, last));
+    }
+    for mask in 1..1usize << n {
+        for last in (0..n).filter(|last| mask & (1 << last) != 0) {
+            let Some((so_far, _)) = best[mask * n + last] else {
+                continue;
+            };
+            for (next, waypoint) in waypoints.iter().enumerate() {
+                if mask & (1 << next) != 0 {
+                    continue;
+                }
+                let Some(step) = cost(last + 1, waypoint) else {
+                    continue;
+                };
+                let slot = &mut best[(mask | (1 << next)) * n + next];
+                if slot.is_none_or(|(cost, _)| so_far + step < cost) {
+                    *slot = Some((so_far + step, last));
+                }
+            }
+        }
+    }
+
+    // total cost and the waypoint visited last
+    let full = (1 << n) - 1;
+    let (total, last) = if n == 0 {
+        (cost(0, &end)?, None)
+    } else {
+        (0..n)
+            .filter_map(|last| {
+                let (so_far, _) = best[full * n + last]?;
+                Some((so_far + cost(last + 1, &end)?, Some(last)))
+            })
+            .min_by_key(|(cost, _)| *cost)?
+    };
+
+    let mut order = vec![];
+    let (mut mask, mut cur) = (full, last);
+    while let Some(last) = cur {
+        order.push(last);
+        let (_, prev) = best[mask * n + last].unwrap();
+        mask &= !(1 << last);
+        cur = (mask != 0).then_some(prev);
+    }
+    order.reverse();
+
+    let legs = std::iter::once(0).chain(order.iter().map(|last| last + 1));
+    let targets = order.iter().map(|last| waypoints[*last]).chain([end]);
+    let mut path = vec![start];
+    for (search, to) in legs.zip(targets) {
+        let from = if search == 0 {
+            start
+        } else {
+            waypoints[search - 1]
+        };
+        let leg = DijkstraMap::<T, T::Index>::extract_path(&from, &to, &searches[search]).ok()?;
+        path.extend(leg.into_iter().skip(1));
+    }
+
+    Some(WaypointTour {
+        cost: total,
+        order: order.into_iter().map(|last| waypoints[last]).collect(),
+        path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_graphs::Edges;
+
+    /// Undirected line `0 - 1 - ... - 9`, each step costing 1.
+    fn line() -> Edges {
+        let mut edges = vec![];
+        for n in 0..9 {
+            edges.push((n, 1, n + 1));
+            edges.push((n + 1, 1, n));
+        }
+        Edges(edges)
+    }
+
+    #[test]
+    fn tour_on_a_line() {
+        let tour = shortest_tour(&line(), 4, 5, &[7, 1]).unwrap();
+        // 4 -> 1 -> 7 -> 5 costs 3 + 6 + 2, going right first costs more
+        assert_eq!(tour.cost, 11);
+        assert_eq!(tour.order, vec![1, 7]);
+        assert_eq!(tour.path, vec![4, 3, 2, 1, 2, 3, 4, 5, 6, 7, 6, 5]);
+    }
+
+    #[test]
+    fn tour_without_waypoints() {
+        let tour = shortest_tour(&line(), 2, 5, &[]).unwrap();
+        assert_eq!(tour.cost, 3);
+        assert!(tour.order.is_empty());
+        assert_eq!(tour.path, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn tour_matches_brute_force() {
+        // small directed graph where the order matters
+        let edges = Edges(vec![
+            (0, 2, 1),
+            (0, 9, 2),
+            (1, 3, 2),
+            (2, 1, 1),
+            (1, 7, 3),
+            (2, 2, 3),
+            (3, 1, 4),
+            (3, 6, 1),
+            (4, 2, 0),
+        ]);
+        let waypoints = [1, 2, 3];
+        let tour = shortest_tour(&edges, 0, 4, &waypoints).unwrap();
+
+        let orders = [
+            [1, 2, 3],
+            [1, 3, 2],
+            [2, 1, 3],
+            [2, 3, 1],
+            [3, 1, 2],
+            [3, 2, 1],
+        ];
+        let best = orders
+            .iter()
+            .filter_map(|order| {
+                let stops = std::iter::once(0)
+                    .chain(*order)
+                    .chain([4])
+                    .collect::<Vec<_>>();
+                stops
+                    .windows(2)
+                    .map(|leg| {
+                        let mut dmap =
+                            DijkstraMap::<_, i32>::new(&edges, DijkstraConfig::default());
+                        dmap.run((0, leg[0])).get(&leg[1]).map(|(cost, _)| *cost)
+                    })
+                    .sum::<Option<i32>>()
+            })
+            .min()
+            .unwrap();
+        assert_eq!(tour.cost, best);
+        assert_eq!(tour.cost, 8);
+        assert_eq!(tour.order, vec![1, 2, 3]);
+        assert_eq!(tour.path, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn tour_unreachable_waypoint() {
+        let edges = Edges(vec![(0, 1, 1), (1, 1, 2)]);
+        assert_eq!(shortest_tour(&edges, 0, 2, &[3]), None);
+        assert_eq!(shortest_tour(&edges, 2, 0, &[]), None);
+    }
+
+    #[test]
+    fn tour_too_many_waypoints() {
+        let edges = line();
+        assert_eq!(shortest_tour(&edges, 0, 2, &[1; MAX_WAYPOINTS + 1]), None);
+    }
+}